@@ -0,0 +1,215 @@
+use utils::Solution;
+
+const USAGE: &str = "usage: aoc run [--day <1-19>]... [--part <1|2>] [--input <path>]
+
+Runs the given days (all of them by default) and prints their answers.
+Each day reads `dayN/input.txt` unless `--input` is given for a single day,
+`--input -` reads the input from stdin.";
+
+type Day = u8;
+
+const DAYS: std::ops::RangeInclusive<Day> = 1..=19;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Part {
+    One,
+    Two,
+}
+
+#[derive(Debug, PartialEq)]
+struct Run {
+    days: Vec<Day>,
+    parts: Vec<Part>,
+    input: Option<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Run, String> {
+    let mut args = args.iter().map(String::as_str);
+
+    match args.next() {
+        Some("run") => {}
+        Some(command) => return Err(format!("unknown command `{command}`")),
+        None => return Err("missing command".into()),
+    }
+
+    let mut days = vec![];
+    let mut parts = vec![];
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for `{arg}`"))?;
+
+        match arg {
+            "--day" => {
+                let day = value
+                    .parse::<Day>()
+                    .ok()
+                    .filter(|day| DAYS.contains(day))
+                    .ok_or_else(|| format!("invalid day `{value}`"))?;
+                days.push(day);
+            }
+            "--part" => {
+                let part = match value {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return Err(format!("invalid part `{value}`")),
+                };
+                parts.push(part);
+            }
+            "--input" => input = Some(value.to_string()),
+            _ => return Err(format!("unknown argument `{arg}`")),
+        }
+    }
+
+    if input.is_some() && days.len() != 1 {
+        return Err("`--input` needs exactly one `--day`".into());
+    }
+
+    if days.is_empty() {
+        days.extend(DAYS);
+    }
+
+    if parts.is_empty() {
+        parts.extend([Part::One, Part::Two]);
+    }
+
+    Ok(Run { days, parts, input })
+}
+
+fn answer<S: Solution>(part: Part, input: &str) -> Result<Option<String>, utils::SolveError> {
+    match part {
+        Part::One => S::part_one(input).map(|answer| Some(answer.to_string())),
+        Part::Two => S::part_two(input).map(|answer| answer.map(|answer| answer.to_string())),
+    }
+}
+
+fn default_input_path(day: Day) -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day}"))
+        .join("input.txt")
+}
+
+fn solve(day: Day, part: Part, input: &str) -> Result<Option<String>, utils::SolveError> {
+    match day {
+        1 => answer::<day1::Day1>(part, input),
+        2 => answer::<day2::Day2>(part, input),
+        3 => answer::<day3::Day3>(part, input),
+        4 => answer::<day4::Day4>(part, input),
+        5 => answer::<day5::Day5>(part, input),
+        6 => answer::<day6::Day6>(part, input),
+        7 => answer::<day7::Day7>(part, input),
+        8 => answer::<day8::Day8>(part, input),
+        9 => answer::<day9::Day9>(part, input),
+        10 => answer::<day10::Day10>(part, input),
+        11 => answer::<day11::Day11>(part, input),
+        12 => answer::<day12::Day12>(part, input),
+        13 => answer::<day13::Day13>(part, input),
+        14 => answer::<day14::Day14>(part, input),
+        15 => answer::<day15::Day15>(part, input),
+        16 => answer::<day16::Day16>(part, input),
+        17 => answer::<day17::Day17>(part, input),
+        18 => answer::<day18::Day18>(part, input),
+        19 => answer::<day19::Day19>(part, input),
+        _ => unreachable!(),
+    }
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let Run { days, parts, input } = match parse_args(&args) {
+        Ok(run) => run,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+
+    let mut failed = false;
+
+    for day in days {
+        let path = match &input {
+            Some(path) => path.into(),
+            None => default_input_path(day),
+        };
+
+        let input = match utils::read_input_from(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("day {day}: failed to read {}: {err}", path.display());
+                failed = true;
+                continue;
+            }
+        };
+
+        for part in parts.iter().copied() {
+            let part_number = match part {
+                Part::One => 1,
+                Part::Two => 2,
+            };
+
+            match solve(day, part, &input) {
+                Ok(Some(answer)) => println!("day {day} part {part_number}: {answer}"),
+                Ok(None) => println!("day {day} part {part_number}: not solved"),
+                Err(err) => {
+                    eprintln!("day {day} part {part_number}: {err}");
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(v: &str) -> Vec<String> {
+        v.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args_defaults_to_everything() {
+        let run = parse_args(&args("run")).unwrap();
+        assert_eq!(run.days, DAYS.collect::<Vec<_>>());
+        assert_eq!(run.parts, vec![Part::One, Part::Two]);
+    }
+
+    #[test]
+    fn test_parse_args_subset() {
+        assert_eq!(
+            parse_args(&args("run --day 17 --part 2 --day 3")).unwrap(),
+            Run {
+                days: vec![17, 3],
+                parts: vec![Part::Two],
+                input: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_args_input() {
+        assert_eq!(
+            parse_args(&args("run --day 5 --input -")).unwrap().input,
+            Some("-".into())
+        );
+        assert!(parse_args(&args("run --input input.txt")).is_err());
+        assert!(parse_args(&args("run --day 1 --day 2 --input input.txt")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("walk")).is_err());
+        assert!(parse_args(&args("run --day 26")).is_err());
+        assert!(parse_args(&args("run --part 3")).is_err());
+        assert!(parse_args(&args("run --day")).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+mod part_one {
+    use utils::{parse::parse_lines, ParseError};
+
+    pub fn part_one(input: &str) -> Result<u32, ParseError> {
+        Ok(parse_lines(input, super::parse_line)?
+            .into_iter()
+            .map(extract_calibration_value)
+            .sum())
+    }
+
+    fn extract_calibration_value(value: &str) -> u32 {
+        let first_num = value.chars().find(|char| char.is_ascii_digit()).unwrap();
+        let last_num = value
+            .chars()
+            .rev()
+            .find(|char| char.is_ascii_digit())
+            .unwrap();
+        format!("{first_num}{last_num}").parse().unwrap()
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&super::input()), Ok(53194));
+    }
+}
+
+mod part_two {
+    use utils::{parse::parse_lines, ParseError};
+
+    pub fn part_two(input: &str) -> Result<u32, ParseError> {
+        Ok(parse_lines(input, super::parse_line)?
+            .into_iter()
+            .map(extract_real_calibration_value)
+            .sum())
+    }
+
+    fn extract_real_calibration_value(value: &str) -> u32 {
+        let nums = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+
+        fn spelling_to_num(value: &str) -> u32 {
+            match value {
+                "one" => 1,
+                "two" => 2,
+                "three" => 3,
+                "four" => 4,
+                "five" => 5,
+                "six" => 6,
+                "seven" => 7,
+                "eight" => 8,
+                "nine" => 9,
+                _ => unreachable!(),
+            }
+        }
+
+        let first_num: u32 = {
+            let maybe_first_num_1 = nums
+                .into_iter()
+                .map(|num| {
+                    value
+                        .find(num)
+                        .map(|idx| (idx, &value[idx..(idx + num.len())]))
+                })
+                .filter(Option::is_some)
+                .map(Option::unwrap)
+                .min_by_key(|(idx, _)| *idx);
+
+            let (idx_2, first_num_2) = value
+                .chars()
+                .enumerate()
+                .find(|(_idx, char)| char.is_ascii_digit())
+                .unwrap();
+
+            {
+                match maybe_first_num_1 {
+                    Some((idx_1, first_num_1)) => {
+                        if idx_1 < idx_2 {
+                            spelling_to_num(&first_num_1)
+                        } else {
+                            first_num_2.to_string().parse().unwrap()
+                        }
+                    }
+                    None => first_num_2.to_string().parse().unwrap(),
+                }
+            }
+        };
+
+        let last_num: u32 = {
+            let nums_reversed = nums.map(|v| v.chars().rev().collect::<String>());
+
+            let maybe_last_num_1 = nums_reversed
+                .into_iter()
+                .map(|num| {
+                    value
+                        .chars()
+                        .rev()
+                        .collect::<String>()
+                        .find(num.as_str())
+                        .map(|idx| {
+                            (
+                                idx,
+                                value.chars().rev().collect::<String>()[idx..(idx + num.len())]
+                                    .chars()
+                                    .rev()
+                                    .collect::<String>(),
+                            )
+                        })
+                })
+                .filter(Option::is_some)
+                .map(Option::unwrap)
+                .min_by_key(|(idx, _)| *idx);
+
+            let (idx_2, last_num_2) = value
+                .chars()
+                .rev()
+                .enumerate()
+                .find(|(_idx, char)| char.is_ascii_digit())
+                .unwrap();
+
+            {
+                match maybe_last_num_1 {
+                    Some((idx_1, last_num_1)) => {
+                        if idx_1 < idx_2 {
+                            spelling_to_num(&last_num_1)
+                        } else {
+                            last_num_2.to_string().parse().unwrap()
+                        }
+                    }
+                    None => last_num_2.to_string().parse().unwrap(),
+                }
+            }
+        };
+
+        first_num * 10 + last_num
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(extract_real_calibration_value("46threevqs8114"), 44);
+        assert_eq!(
+            extract_real_calibration_value("sevenntgvnrrqfvxh2ttnkgffour8fiveone"),
+            71
+        );
+        assert_eq!(
+            extract_real_calibration_value("fzrpfhbfvj6dbxbtfs7twofksfbshrzkdeightwoqg"),
+            62
+        );
+
+        assert_eq!(part_two(&super::input()), Ok(54249));
+    }
+}
+
+// both parts need at least one plain digit on every line
+fn parse_line(value: &str) -> Result<&str, utils::ParseError> {
+    if value.chars().any(|char| char.is_ascii_digit()) {
+        Ok(value)
+    } else {
+        Err(utils::ParseError::new(
+            1,
+            value.chars().count() + 1,
+            "a digit",
+        ))
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+pub struct Day1;
+
+impl utils::Solution for Day1 {
+    type Answer = u32;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_without_digit() {
+        assert_eq!(
+            utils::parse::parse_lines("a1\nabc", parse_line),
+            Err(utils::ParseError::new(2, 4, "a digit"))
+        );
+    }
+}
@@ -0,0 +1,534 @@
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<usize, utils::ParseError> {
+        let space = input.parse::<Space>()?;
+        let ring = space.ring()?;
+        Ok(ring.len() / 2)
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(6942));
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one_examples() {
+        assert_eq!(part_one("-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF"), Ok(4));
+        assert_eq!(part_one("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ..."), Ok(8));
+    }
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<usize, utils::ParseError> {
+        let space = input.parse::<Space>()?;
+        let ring = space.ring()?;
+
+        // both ways of counting agree on any loop, `test_enclosed` covers it too
+        let enclosed = space.enclosed(&ring).len();
+        debug_assert_eq!(enclosed, Space::enclosed_count(&ring));
+        Ok(enclosed)
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(297));
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two_examples() {
+        let squeezed = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+        assert_eq!(part_two(squeezed), Ok(4));
+
+        let junk = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        assert_eq!(part_two(junk), Ok(10));
+
+        let space = junk.parse::<Space>().unwrap();
+        let ring = space.ring().unwrap();
+        assert_eq!(
+            space.render(&ring).lines().nth(4).unwrap(),
+            "└───┘┌─┘└┘IIII┌┘└┘OO"
+        );
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_enclosed() {
+        let space = input().parse::<Space>().unwrap();
+        let ring = space.ring().unwrap();
+
+        let enclosed = space.enclosed(&ring);
+        assert_eq!(enclosed.len(), Space::enclosed_count(&ring));
+        assert!(enclosed.iter().all(|pos| !ring.contains(pos)));
+
+        let rendered = space.render(&ring);
+        assert_eq!(rendered.matches('I').count(), enclosed.len());
+        assert_eq!(rendered.matches('S').count(), 1);
+        assert_eq!(
+            rendered.chars().filter(|c| !"IO\n".contains(*c)).count(),
+            ring.len()
+        );
+    }
+}
+
+pub struct Space {
+    grid: utils::Grid<Cell>,
+    start: Pos,
+    // the pipe under `S`, connecting its only two connected neighbours
+    start_pipe: Node,
+}
+
+impl Space {
+    // the loop through `S`, starting there
+    pub fn ring(&self) -> Result<Vec<Pos>, utils::ParseError> {
+        let (tiles, closed) = self.follow(self.start, self.start_pipe.0);
+        if !closed {
+            let (x, y) = (self.start.x as usize, self.start.y as usize);
+            return Err(utils::ParseError::new(
+                y + 1,
+                x + 1,
+                "`S` on a closed loop of pipes",
+            ));
+        }
+
+        Ok(std::iter::once(self.start).chain(tiles).collect())
+    }
+
+    // every closed loop and every chain of pipes with open ends, in reading order of their first tile
+    pub fn network(&self) -> Network {
+        let mut visited = self.map(|_| false);
+        let mut network = Network::default();
+
+        for (pos, _) in self.iter() {
+            let Some(pipe) = self.pipe(pos) else {
+                continue;
+            };
+            if visited.get(pos) == Some(&true) {
+                continue;
+            }
+
+            let (forward, closed) = self.follow(pos, pipe.0);
+            let tiles = if closed {
+                std::iter::once(pos).chain(forward).collect::<Vec<_>>()
+            } else {
+                let (backward, _) = self.follow(pos, pipe.1);
+                backward
+                    .into_iter()
+                    .rev()
+                    .chain(std::iter::once(pos))
+                    .chain(forward)
+                    .collect()
+            };
+
+            for tile in &tiles {
+                *visited.get_mut(*tile).unwrap() = true;
+            }
+            if closed {
+                network.loops.push(Loop {
+                    enclosed: Self::enclosed_count(&tiles),
+                    tiles,
+                });
+            } else {
+                network.chains.push(tiles);
+            }
+        }
+
+        network
+    }
+
+    // The tiles after `from` along the pipes going out `direction`,
+    // and whether they lead back to `from` rather than to an open end.
+    fn follow(&self, from: Pos, mut direction: Dir4) -> (Vec<Pos>, bool) {
+        let mut tiles = vec![];
+        let mut pos = from;
+
+        while self.linked(pos, direction) {
+            pos = pos.step(direction);
+            if pos == from {
+                return (tiles, true);
+            }
+            tiles.push(pos);
+            direction = self
+                .pipe(pos)
+                .and_then(|pipe| pipe.opposite_direction(direction.reverse()))
+                .unwrap();
+        }
+
+        (tiles, false)
+    }
+
+    // when the pipes on both sides connect
+    fn linked(&self, pos: Pos, direction: Dir4) -> bool {
+        let leads = |pos, direction| self.pipe(pos).is_some_and(|pipe| pipe.leads_to(direction));
+        leads(pos, direction) && leads(pos.step(direction), direction.reverse())
+    }
+
+    // the inferred one under `S`, `None` on the ground and outside of the grid
+    pub fn pipe(&self, pos: Pos) -> Option<Node> {
+        match self.get_cell(pos) {
+            Cell::Node(node) => Some(node),
+            Cell::Start => Some(self.start_pipe),
+            Cell::Ground => None,
+        }
+    }
+
+    // by the shoelace formula and Pick's theorem, without listing them
+    pub fn enclosed_count(ring: &[Pos]) -> usize {
+        let total_area = ring
+            .iter()
+            .zip(ring.iter().cycle().skip(1))
+            // https://en.wikipedia.org/wiki/Shoelace_formula, see Example
+            .map(|(l, r)| l.x * r.y - r.x * l.y)
+            .sum::<isize>()
+            .unsigned_abs()
+            / 2;
+
+        // derived from https://en.wikipedia.org/wiki/Pick%27s_theorem
+        // A = i + b / 2 - 1
+        // i = A - b / 2 + 1
+        total_area + 1 - ring.len() / 2
+    }
+
+    // in reading order
+    pub fn enclosed(&self, ring: &[Pos]) -> Vec<Pos> {
+        // a tile is inside when a ray from it to the left crosses the ring an odd number of times,
+        // crossing it where the ring goes north so that `L-7` counts once and `L-J` twice or not at all
+        let mut north = utils::Grid::new(self.width(), self.height(), None);
+        for (i, pos) in ring.iter().enumerate() {
+            let previous = ring[(i + ring.len() - 1) % ring.len()];
+            let next = ring[(i + 1) % ring.len()];
+            let above = pos.step(Dir4::North);
+            *north.get_mut(*pos).unwrap() = Some(previous == above || next == above);
+        }
+
+        let mut enclosed = vec![];
+        for (y, row) in north.rows().enumerate() {
+            let mut inside = false;
+            for (x, ring_north) in row.iter().enumerate() {
+                match ring_north {
+                    Some(true) => inside = !inside,
+                    Some(false) => {}
+                    None if inside => enclosed.push(Pos::new(x as isize, y as isize)),
+                    None => {}
+                }
+            }
+        }
+        enclosed
+    }
+
+    // the ring in box drawing characters, the tiles it encloses as `I` and the others as `O`
+    pub fn render(&self, ring: &[Pos]) -> String {
+        let mut rendered = self.map(|_| 'O');
+        for pos in self.enclosed(ring) {
+            *rendered.get_mut(pos).unwrap() = 'I';
+        }
+        for pos in ring {
+            *rendered.get_mut(*pos).unwrap() = match self.get_cell(*pos) {
+                Cell::Node(node) => node.box_char(),
+                _ => 'S',
+            };
+        }
+        rendered.to_string()
+    }
+
+    // outside of the grid is all ground
+    pub fn get_cell(&self, pos: Pos) -> Cell {
+        self.get(pos).copied().unwrap_or(Cell::Ground)
+    }
+
+    pub fn start(&self) -> Pos {
+        self.start
+    }
+
+    pub fn start_pipe(&self) -> Node {
+        self.start_pipe
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Network {
+    pub loops: Vec<Loop>,
+    // from one open end to the other, a lone pipe connected to nothing is a chain too
+    pub chains: Vec<Vec<Pos>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Loop {
+    // in the order they connect
+    pub tiles: Vec<Pos>,
+    // how many tiles it encloses
+    pub enclosed: usize,
+}
+
+impl std::ops::Deref for Space {
+    type Target = utils::Grid<Cell>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.grid
+    }
+}
+
+#[derive(Debug, strum::EnumIs, Clone, Copy)]
+pub enum Cell {
+    Node(Node),
+    Start,
+    Ground,
+}
+
+impl Cell {
+    pub fn leads_to(&self, direction: Dir4) -> bool {
+        match self {
+            Cell::Start => false,
+            Cell::Ground => false,
+            Cell::Node(node) => node.leads_to(direction),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Node(pub Dir4, pub Dir4);
+
+impl Node {
+    pub fn leads_to(&self, direction: Dir4) -> bool {
+        self.0 == direction || self.1 == direction
+    }
+
+    pub fn box_char(&self) -> char {
+        use Dir4::*;
+        match (
+            self.leads_to(North),
+            self.leads_to(East),
+            self.leads_to(South),
+        ) {
+            (true, _, true) => '│',
+            (true, true, _) => '└',
+            (true, _, _) => '┘',
+            (_, true, true) => '┌',
+            (_, _, true) => '┐',
+            _ => '─',
+        }
+    }
+
+    pub fn opposite_direction(&self, direction: Dir4) -> Option<Dir4> {
+        if self.0 == direction {
+            Some(self.1)
+        } else if self.1 == direction {
+            Some(self.0)
+        } else {
+            None
+        }
+    }
+}
+
+type Pos = Point;
+
+impl std::str::FromStr for Space {
+    type Err = utils::ParseError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Dir4::*;
+        let grid = utils::Grid::parse(input, "a pipe, `.` or `S`", |char| {
+            Some(match char {
+                '.' => Cell::Ground,
+                'S' => Cell::Start,
+                '|' => Cell::Node(Node(South, North)),
+                '-' => Cell::Node(Node(West, East)),
+                'L' => Cell::Node(Node(North, East)),
+                'J' => Cell::Node(Node(North, West)),
+                '7' => Cell::Node(Node(South, West)),
+                'F' => Cell::Node(Node(South, East)),
+                _ => return None,
+            })
+        })?;
+
+        let at = |pos: Pos, expected| {
+            utils::ParseError::new(pos.y as usize + 1, pos.x as usize + 1, expected)
+        };
+
+        let starts = grid
+            .iter()
+            .filter(|(_, cell)| cell.is_start())
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        let start = match starts[..] {
+            [start] => start,
+            [] => return Err(utils::ParseError::at(input, "", "a start `S`")),
+            [_, second, ..] => return Err(at(second, "a single start `S`")),
+        };
+
+        // outside of the grid nothing connects
+        let connected = Dir4::ALL
+            .into_iter()
+            .filter(|direction| {
+                grid.get(start.step(*direction))
+                    .is_some_and(|cell| cell.leads_to(direction.reverse()))
+            })
+            .collect::<Vec<_>>();
+        let [first, second] = connected[..] else {
+            return Err(at(start, "`S` connected to exactly two pipes"));
+        };
+
+        Ok(Space {
+            grid,
+            start,
+            start_pipe: Node(first, second),
+        })
+    }
+}
+
+use utils::{Dir4, Point};
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+pub struct Day10;
+
+impl utils::Solution for Day10 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_pipe() {
+        let space = "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF"
+            .parse::<Space>()
+            .unwrap();
+        assert_eq!(space.start(), Pos::new(1, 1));
+        assert_eq!(space.start_pipe().box_char(), '┌');
+
+        // on the border
+        let space = "S7\nLJ".parse::<Space>().unwrap();
+        assert_eq!(space.start_pipe().box_char(), '┌');
+        assert_eq!(space.ring().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_start_errors() {
+        let error = |input: &str| input.parse::<Space>().err();
+
+        assert_eq!(
+            error("F7\nLJ"),
+            Some(utils::ParseError::new(2, 3, "a start `S`"))
+        );
+        assert_eq!(
+            error("S7\nLS"),
+            Some(utils::ParseError::new(2, 2, "a single start `S`"))
+        );
+        // four, one and no connected pipes
+        for (input, line, column) in [
+            (".|.\n-S-\n.|.", 2, 2),
+            ("S-\n..", 1, 1),
+            (".S.\n...", 1, 2),
+        ] {
+            assert_eq!(
+                error(input),
+                Some(utils::ParseError::new(
+                    line,
+                    column,
+                    "`S` connected to exactly two pipes"
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn test_network() {
+        let space = "\
+S7.F-7
+LJ.|.|
+-..L-J
+.F-7.."
+            .parse::<Space>()
+            .unwrap();
+        let pos = |x, y| Pos::new(x, y);
+
+        assert_eq!(
+            space.network(),
+            Network {
+                loops: vec![
+                    Loop {
+                        tiles: vec![pos(0, 0), pos(1, 0), pos(1, 1), pos(0, 1)],
+                        enclosed: 0
+                    },
+                    Loop {
+                        tiles: vec![
+                            pos(3, 0),
+                            pos(3, 1),
+                            pos(3, 2),
+                            pos(4, 2),
+                            pos(5, 2),
+                            pos(5, 1),
+                            pos(5, 0),
+                            pos(4, 0)
+                        ],
+                        enclosed: 1
+                    }
+                ],
+                chains: vec![vec![pos(0, 2)], vec![pos(3, 3), pos(2, 3), pos(1, 3)]],
+            }
+        );
+
+        let network = input().parse::<Space>().unwrap().network();
+        let main = network.loops.iter().max_by_key(|l| l.tiles.len()).unwrap();
+        assert_eq!((main.tiles.len(), main.enclosed), (13884, 297));
+    }
+
+    #[test]
+    fn test_broken_ring() {
+        let space = "S7\nL-".parse::<Space>().unwrap();
+        assert_eq!(
+            space.ring(),
+            Err(utils::ParseError::new(
+                1,
+                1,
+                "`S` on a closed loop of pipes"
+            ))
+        );
+        assert_eq!(
+            space.network().chains,
+            [vec![
+                Pos::new(1, 1),
+                Pos::new(0, 1),
+                Pos::new(0, 0),
+                Pos::new(1, 0)
+            ]]
+        );
+    }
+}
@@ -0,0 +1,261 @@
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<u128, utils::ParseError> {
+        let space = input.parse::<Space>()?;
+        Ok(space.distance_sum(1))
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(9686930));
+    }
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<u128, utils::ParseError> {
+        let space = input.parse::<Space>()?;
+        Ok(space.distance_sum(10usize.pow(6) - 1))
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(630728425490));
+    }
+}
+
+impl Space {
+    // The sum of `shortest_distances` in O(n log n) for n galaxies:
+    // the distance splits into one along each axis, summed over the sorted coordinates.
+    pub fn distance_sum(&self, expansion_multiplier: usize) -> u128 {
+        let galaxies = self.galaxies();
+
+        let xs = expanded(
+            galaxies.iter().map(|pos| pos.x as usize),
+            self.width(),
+            &self.empty_columns(),
+            expansion_multiplier,
+        );
+        let ys = expanded(
+            galaxies.iter().map(|pos| pos.y as usize),
+            self.height(),
+            &self.empty_rows(),
+            expansion_multiplier,
+        );
+
+        pairwise_distance_sum(xs) + pairwise_distance_sum(ys)
+    }
+
+    // compares every pair, see `distance_sum` for a faster total
+    pub fn shortest_distances(&self, expansion_multiplier: usize) -> impl Iterator<Item = usize> {
+        let space = self;
+
+        let empty_rows = space.empty_rows();
+        let empty_columns = space.empty_columns();
+
+        let galaxy_pairs = space.galaxy_pairs();
+
+        galaxy_pairs.into_iter().map(move |(l, r)| {
+            shortest_distance(l, r, expansion_multiplier, &empty_rows, &empty_columns)
+        })
+    }
+}
+
+// `coordinates` after every empty line before them grows by `expansion_multiplier`
+fn expanded(
+    coordinates: impl Iterator<Item = usize>,
+    length: usize,
+    empty: &[usize],
+    expansion_multiplier: usize,
+) -> Vec<u128> {
+    let mut is_empty = vec![false; length];
+    for &i in empty {
+        is_empty[i] = true;
+    }
+
+    let mut shift = vec![0; length];
+    let mut empty_before = 0;
+    for (shift, is_empty) in shift.iter_mut().zip(is_empty) {
+        *shift = empty_before as u128 * expansion_multiplier as u128;
+        empty_before += usize::from(is_empty);
+    }
+
+    coordinates.map(|i| i as u128 + shift[i]).collect()
+}
+
+// of `|a - b|` over every pair, each item is subtracted by the ones before it once sorted
+fn pairwise_distance_sum(mut coordinates: Vec<u128>) -> u128 {
+    coordinates.sort_unstable();
+
+    let mut before = 0;
+    coordinates
+        .iter()
+        .enumerate()
+        .map(|(i, coordinate)| {
+            let distances = coordinate * i as u128 - before;
+            before += coordinate;
+            distances
+        })
+        .sum()
+}
+
+pub fn shortest_distance(
+    l: Pos,
+    r: Pos,
+    expansion_multiplier: usize,
+    empty_rows: &[usize],
+    empty_columns: &[usize],
+) -> usize {
+    fn between(i: usize, a: isize, b: isize) -> bool {
+        let i = i as isize;
+        i > a.min(b) && i < a.max(b)
+    }
+
+    let x_expansion = empty_columns
+        .iter()
+        .filter(|i| between(**i, l.x, r.x))
+        .count()
+        * expansion_multiplier;
+
+    let y_expansion =
+        empty_rows.iter().filter(|i| between(**i, l.y, r.y)).count() * expansion_multiplier;
+
+    l.manhattan(r) + x_expansion + y_expansion
+}
+
+impl Space {
+    pub fn galaxy_pairs(&self) -> Vec<(Pos, Pos)> {
+        let galaxies = self.galaxies();
+
+        (0..galaxies.len())
+            .map(|i| (0..galaxies.len()).map(move |j| (i, j)))
+            .flatten()
+            .filter(|(i, j)| i != j && i < j)
+            .map(|(i, j)| (galaxies[i], galaxies[j]))
+            .collect()
+    }
+
+    pub fn galaxies(&self) -> Vec<Pos> {
+        self.iter()
+            .filter(|(_, cell)| cell.is_universe())
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    pub fn empty_rows(&self) -> Vec<usize> {
+        self.rows()
+            .enumerate()
+            .filter(|(_i, row)| row.iter().all(|cell| cell.is_empty()))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    pub fn empty_columns(&self) -> Vec<usize> {
+        (0..self.width())
+            .filter(|x| self.column(*x).all(|cell| cell.is_empty()))
+            .collect()
+    }
+}
+
+#[derive(strum::EnumIs, Clone, Copy)]
+pub enum Cell {
+    Empty,
+    Universe,
+}
+
+type Pos = utils::Point;
+
+pub struct Space(utils::Grid<Cell>);
+
+impl std::str::FromStr for Space {
+    type Err = utils::ParseError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        utils::Grid::parse(input, "`.` or `#`", parse_cell).map(Self)
+    }
+}
+
+impl std::ops::Deref for Space {
+    type Target = utils::Grid<Cell>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+fn parse_cell(char: char) -> Option<Cell> {
+    match char {
+        '.' => Some(Cell::Empty),
+        '#' => Some(Cell::Universe),
+        _ => None,
+    }
+}
+
+pub struct Day11;
+
+impl utils::Solution for Day11 {
+    type Answer = u128;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn test_distance_sum() {
+        let space = EXAMPLE.parse::<Space>().unwrap();
+        assert_eq!(space.distance_sum(1), 374);
+        assert_eq!(space.distance_sum(9), 1030);
+        assert_eq!(space.distance_sum(99), 8410);
+
+        let space = input().parse::<Space>().unwrap();
+        for expansion_multiplier in [0, 1, 9, 999_999] {
+            assert_eq!(
+                space.distance_sum(expansion_multiplier),
+                space
+                    .shortest_distances(expansion_multiplier)
+                    .sum::<usize>() as u128
+            );
+        }
+    }
+
+    #[test]
+    fn test_distance_sum_many_galaxies() {
+        // 360000 galaxies and no empty lines, every pair of columns is there for every pair of rows
+        let side = 600;
+        let input = vec!["#".repeat(side); side].join("\n");
+        let space = input.parse::<Space>().unwrap();
+
+        let side = side as u128;
+        let one_axis = side * side * (side.pow(3) - side) / 6;
+        assert_eq!(space.distance_sum(1_000_000), 2 * one_axis);
+    }
+}
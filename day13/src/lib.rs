@@ -0,0 +1,328 @@
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<usize, utils::ParseError> {
+        Ok(parse_input(input)?
+            .into_iter()
+            .map(calc_group)
+            .sum::<usize>())
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(30705));
+    }
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<usize, utils::ParseError> {
+        Ok(parse_input(input)?
+            .into_iter()
+            .map(calc_group_smudged)
+            .sum::<usize>())
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(44615));
+    }
+}
+
+type Index = usize;
+type AB = (Index, Index);
+
+type Group = utils::Grid<Cell>;
+type Input = Vec<Group>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Ash,
+    Rock,
+}
+
+// columns of a group are the rows of its transpose
+fn check_full_col_reflection(group: &Group, ab: AB) -> Option<usize> {
+    check_full_reflection(&group.transpose(), ab)
+}
+
+fn check_full_row_reflection(group: &Group, ab: AB) -> Option<usize> {
+    check_full_reflection(group, ab)
+}
+
+fn check_full_reflection(group: &Group, (initial_a, initial_b): AB) -> Option<usize> {
+    fn check_reflection(group: &Group, (a, b): AB) -> bool {
+        group.row(a) == group.row(b)
+    }
+
+    let (mut a, mut b) = (Some(initial_a), Some(initial_b));
+
+    loop {
+        match (a, b) {
+            (None, None) => unreachable!(),
+            (Some(_a), Some(_b)) => {
+                if !check_reflection(group, (_a, _b)) {
+                    return None;
+                }
+
+                (a, b) = move_away_indeces((_a, _b), 1, group.height());
+            }
+            (Some(_), None) | (None, Some(_)) => {
+                return Some(initial_a + 1);
+            }
+        }
+    }
+}
+
+fn calc_group(group: Group) -> usize {
+    let col_indeces = generate_initital_column_indeces(&group);
+
+    let col_reflection = col_indeces
+        .into_iter()
+        .find_map(|(l, r)| check_full_col_reflection(&group, (l, r)));
+
+    let row_indeces = generate_initial_row_indeces(&group);
+
+    let row_reflection = row_indeces
+        .into_iter()
+        .find_map(|(l, r)| check_full_row_reflection(&group, (l, r)));
+
+    col_reflection.unwrap_or(0) + row_reflection.unwrap_or(0) * 100
+}
+
+fn check_full_col_reflection_smudged(group: &Group, ab: AB) -> Option<usize> {
+    check_full_reflection_smudged(&group.transpose(), ab)
+}
+
+fn check_full_row_reflection_smudged(group: &Group, ab: AB) -> Option<usize> {
+    check_full_reflection_smudged(group, ab)
+}
+
+fn check_full_reflection_smudged(group: &Group, (initial_a, initial_b): AB) -> Option<usize> {
+    type FixedSmuged = bool;
+    fn check_reflection_smudged(group: &Group, (a, b): AB) -> (bool, FixedSmuged) {
+        let a = group.row(a);
+        let b = group.row(b);
+
+        let eq = a.iter().zip(b).filter(|(a, b)| a == b).count();
+
+        if eq == a.len() {
+            (true, false)
+        } else if eq + 1 == a.len() {
+            (true, true)
+        } else {
+            (false, false)
+        }
+    }
+
+    let (mut a, mut b) = (Some(initial_a), Some(initial_b));
+
+    let mut fixed_smudge = false;
+    loop {
+        match (a, b) {
+            (None, None) => unreachable!(),
+            (Some(_a), Some(_b)) => {
+                match check_reflection_smudged(group, (_a, _b)) {
+                    (true, true) => {
+                        if fixed_smudge {
+                            return None;
+                        } else {
+                            fixed_smudge = true;
+                        }
+                    }
+                    (true, false) => {}
+                    (false, false) => {
+                        return None;
+                    }
+                    (false, true) => unreachable!(),
+                }
+
+                (a, b) = move_away_indeces((_a, _b), 1, group.height());
+            }
+            (Some(_), None) | (None, Some(_)) => {
+                return if fixed_smudge {
+                    Some(initial_a + 1)
+                } else {
+                    None
+                };
+            }
+        }
+    }
+}
+
+fn calc_group_smudged(group: Group) -> usize {
+    let col_indeces = generate_initital_column_indeces(&group);
+
+    let col_reflection = col_indeces
+        .into_iter()
+        .find_map(|(l, r)| check_full_col_reflection_smudged(&group, (l, r)));
+
+    let row_indeces = generate_initial_row_indeces(&group);
+
+    let row_reflection = row_indeces
+        .into_iter()
+        .find_map(|(l, r)| check_full_row_reflection_smudged(&group, (l, r)));
+
+    col_reflection.unwrap_or(0) + row_reflection.unwrap_or(0) * 100
+}
+
+fn generate_initital_column_indeces(group: &Group) -> Vec<AB> {
+    generate_initial_indeces(group.width())
+}
+
+fn generate_initial_row_indeces(group: &Group) -> Vec<AB> {
+    generate_initial_indeces(group.height())
+}
+
+fn generate_initial_indeces(count: usize) -> Vec<AB> {
+    (0..count)
+        .collect::<Vec<_>>()
+        .as_slice()
+        .windows(2)
+        .map(|window| {
+            let (l, r) = (window[0], window[1]);
+            (l, r)
+        })
+        .collect()
+}
+
+fn move_away_indeces((a, b): AB, c: usize, upper_limit: usize) -> (Option<usize>, Option<usize>) {
+    (
+        if c > a { None } else { Some(a - c) },
+        if c + b >= upper_limit {
+            None
+        } else {
+            Some(b + c)
+        },
+    )
+}
+
+fn parse_group(value: &str) -> Result<Group, utils::ParseError> {
+    Group::parse(value, "`#` or `.`", |c| match c {
+        '#' => Some(Cell::Rock),
+        '.' => Some(Cell::Ash),
+        _ => None,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Input, utils::ParseError> {
+    let mut line = 0;
+    input
+        .split("\n\n")
+        .map(|group| {
+            let parsed = parse_group(group).map_err(|err| err.offset_lines(line));
+            line += group.lines().count() + 1;
+            parsed
+        })
+        .collect()
+}
+
+pub struct Day13;
+
+impl utils::Solution for Day13 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_group_1() -> &'static str {
+        "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#."
+    }
+
+    fn test_group_2() -> &'static str {
+        "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"
+    }
+
+    #[test]
+    fn test_calc_test_group_1_smudged() {
+        let group = parse_group(test_group_1()).unwrap();
+
+        let row_indeces = generate_initial_row_indeces(&group);
+
+        let r = row_indeces
+            .into_iter()
+            .find_map(|(l, r)| check_full_row_reflection_smudged(&group, (l, r)));
+        assert_eq!(r, Some(3));
+    }
+
+    #[test]
+    fn test_calc_test_group_2_smudged() {
+        let group = parse_group(test_group_2()).unwrap();
+
+        let row_indeces = generate_initial_row_indeces(&group);
+
+        let r = row_indeces
+            .into_iter()
+            .find_map(|(l, r)| check_full_row_reflection_smudged(&group, (l, r)));
+        assert_eq!(r, Some(1));
+    }
+
+    #[test]
+    fn test_calc_test_group_1() {
+        let group = parse_group(test_group_1()).unwrap();
+
+        let col_indeces = generate_initital_column_indeces(&group);
+
+        let r = col_indeces
+            .into_iter()
+            .find_map(|(l, r)| check_full_col_reflection(&group, (l, r)));
+
+        assert_eq!(r, Some(5));
+    }
+
+    #[test]
+    fn test_calc_test_group_2() {
+        let group = parse_group(test_group_2()).unwrap();
+
+        let row_indeces = generate_initial_row_indeces(&group);
+
+        let r = row_indeces
+            .into_iter()
+            .find_map(|(l, r)| check_full_row_reflection(&group, (l, r)));
+
+        assert_eq!(r, Some(4));
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let input = format!(
+            "{}\n\n{}",
+            test_group_1(),
+            test_group_2().replacen('#', "o", 3)
+        );
+        assert_eq!(
+            parse_input(&input),
+            Err(utils::ParseError::new(9, 1, "`#` or `.`"))
+        );
+    }
+}
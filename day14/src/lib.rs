@@ -0,0 +1,174 @@
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<usize, utils::ParseError> {
+        Ok(space_weight(
+            iter_cols(&parse_input(input)?)
+                .map(move_north)
+                .collect::<Space>()
+                .transpose(),
+        ))
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(107142));
+    }
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<usize, utils::ParseError> {
+        fn run_cycle(mut space: Space) -> Space {
+            space = iter_cols(&space).map(move_north).collect();
+            space = space.transpose();
+
+            space = iter_rows(&space).map(move_west).collect();
+
+            space = iter_cols(&space).map(move_south).collect();
+            space = space.transpose();
+
+            space = iter_rows(&space).map(move_east).collect();
+
+            space
+        }
+
+        let space = parse_input(input)?;
+        let mut space: Space = space.clone();
+        let mut seen = vec![space.clone()];
+
+        loop {
+            space = run_cycle(space);
+
+            match seen.iter().position(|_space| _space == &space) {
+                None => seen.push(space.clone()),
+                Some(cycle_start) => {
+                    let cycle_len = seen.len() - cycle_start;
+
+                    let space =
+                        seen[cycle_start + (1_000_000_000 - cycle_start) % cycle_len].clone();
+
+                    return Ok(space_weight(space));
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(104815));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+enum Cell {
+    Empty,
+    RoundedRock,
+    CubeRock,
+}
+
+type CellSeq = Vec<Cell>;
+type Column = CellSeq;
+type Row = CellSeq;
+type Space = utils::Grid<Cell>;
+
+enum MoveTo {
+    Start,
+    End,
+}
+
+fn roll_rocks(cells: CellSeq, move_to: MoveTo) -> CellSeq {
+    use itertools::Itertools;
+
+    cells
+        .clone()
+        .into_iter()
+        .group_by(|c| c.is_cube_rock())
+        .into_iter()
+        .map(|(i, group)| -> Box<dyn Iterator<Item = Cell>> {
+            if i {
+                Box::new(group)
+            } else {
+                let group = group.collect::<Vec<_>>();
+                let round_rock_count = group.iter().filter(|c| c.is_rounded_rock()).count();
+
+                let round_rocks = (0..round_rock_count).map(|_| Cell::RoundedRock);
+                let empty_space = (0..group.len() - round_rock_count).map(|_| Cell::Empty);
+
+                match move_to {
+                    MoveTo::Start => Box::new(round_rocks.chain(empty_space)),
+                    MoveTo::End => Box::new(empty_space.chain(round_rocks)),
+                }
+            }
+        })
+        .flatten()
+        .collect()
+}
+
+fn space_weight(space: Space) -> usize {
+    fn col_weight(col: Column) -> usize {
+        col.clone()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_rounded_rock())
+            .map(|(i, _)| col.len() - i)
+            .sum::<usize>()
+    }
+
+    iter_cols(&space).map(col_weight).sum::<usize>()
+}
+
+fn iter_cols(space: &Space) -> impl Iterator<Item = Column> + '_ {
+    space.columns().map(|col| col.copied().collect())
+}
+
+fn iter_rows(space: &Space) -> impl Iterator<Item = Row> + '_ {
+    space.rows().map(<[Cell]>::to_vec)
+}
+
+fn move_north(col: Column) -> Column {
+    roll_rocks(col, MoveTo::Start)
+}
+
+fn move_west(row: Row) -> Row {
+    roll_rocks(row, MoveTo::Start)
+}
+
+fn move_south(col: Column) -> Column {
+    roll_rocks(col, MoveTo::End)
+}
+
+fn move_east(row: Row) -> Row {
+    roll_rocks(row, MoveTo::End)
+}
+
+fn parse_input(input: &str) -> Result<Space, utils::ParseError> {
+    Space::parse(input, "`#`, `.` or `O`", |c| match c {
+        '#' => Some(Cell::CubeRock),
+        '.' => Some(Cell::Empty),
+        'O' => Some(Cell::RoundedRock),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+pub struct Day14;
+
+impl utils::Solution for Day14 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
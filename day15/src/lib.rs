@@ -0,0 +1,227 @@
+#![allow(unused)]
+
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<u32, utils::ParseError> {
+        Ok(parse_input(input).map(hash).sum::<u32>())
+    }
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(504036));
+    }
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<usize, utils::ParseError> {
+        Ok(focusing_power_of_lense_configuration(parse_steps(input)?))
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(295719));
+    }
+}
+
+fn focusing_power_of_lense_configuration(steps: Vec<Step>) -> usize {
+    steps
+        .into_iter()
+        .fold(
+            (0..256).map(|_| Vec::with_capacity(6)).collect(),
+            |mut boxes: Vec<Vec<Lense>>, step| {
+                match step.operation {
+                    Operation::Equals { focal_len } => {
+                        match boxes[step.hash]
+                            .iter()
+                            .position(|lense| lense.label == step.label)
+                        {
+                            None => boxes[step.hash].push(Lense {
+                                label: step.label,
+                                focal_len,
+                            }),
+                            Some(i) => boxes[step.hash][i].focal_len = focal_len,
+                        }
+                    }
+                    Operation::Dash => {
+                        match boxes[step.hash]
+                            .iter()
+                            .position(|lense| lense.label == step.label)
+                        {
+                            None => {}
+                            Some(i) => {
+                                boxes[step.hash].remove(i);
+                            }
+                        }
+                    }
+                }
+
+                boxes
+            },
+        )
+        .into_iter()
+        .enumerate()
+        .map(|(box_i, a_box)| {
+            a_box
+                .into_iter()
+                .enumerate()
+                .map(move |(lense_i, lense)| (box_i + 1) * (lense_i + 1) * lense.focal_len)
+        })
+        .flatten()
+        .sum::<usize>()
+}
+
+type FocalLen = usize;
+
+#[derive(Debug, Clone)]
+struct Step {
+    pub label: String,
+    pub operation: Operation,
+    pub hash: usize,
+}
+
+impl Step {
+    pub fn new(label: String, operation: Operation) -> Self {
+        let hash = hash(&label) as usize;
+
+        Self {
+            label,
+            operation,
+            hash,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Dash,
+    Equals { focal_len: FocalLen },
+}
+
+#[derive(Clone, Debug)]
+struct Lense {
+    pub label: String,
+    pub focal_len: FocalLen,
+}
+
+fn hash(v: &str) -> u32 {
+    v.chars().map(char_to_ascii_code).fold(0, |acc, x| {
+        let (_div, rem) = num::integer::div_rem((acc + x) * 17, 256);
+        rem
+    })
+}
+
+fn char_to_ascii_code(c: char) -> u32 {
+    c as u32
+}
+
+fn parse_steps(i: &str) -> Result<Vec<Step>, utils::ParseError> {
+    let mut column = 0;
+    parse_input(i)
+        .map(|v| {
+            let step = v.parse::<Step>().map_err(|mut err| {
+                err.column += column;
+                err
+            });
+            column += v.len() + 1;
+            step
+        })
+        .collect()
+}
+
+fn parse_input(i: &str) -> impl Iterator<Item = &str> {
+    i.trim_end().split(",")
+}
+
+impl std::str::FromStr for Step {
+    type Err = utils::ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use utils::ParseError;
+
+        let (label, operation) = if let Some(label) = s.strip_suffix('-') {
+            (label, Operation::Dash)
+        } else {
+            let (label, lense_focal_length) = s
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(1, s.len() + 1, "`-` or `=`"))?;
+            let focal_len = lense_focal_length
+                .parse()
+                .map_err(|_| ParseError::new(1, label.len() + 2, "a focal length"))?;
+            (label, Operation::Equals { focal_len })
+        };
+
+        if label.is_empty() {
+            return Err(ParseError::new(1, 1, "a label"));
+        }
+
+        if let Some(i) = label.find(|c: char| !c.is_ascii_lowercase()) {
+            return Err(ParseError::new(1, i + 1, "a lowercase letter"));
+        }
+
+        Ok(Self::new(label.into(), operation))
+    }
+}
+
+pub struct Day15;
+
+impl utils::Solution for Day15 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)? as usize)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_example() {
+        assert_eq!(hash("HASH"), 52);
+    }
+
+    #[test]
+    fn test_sequence_example() {
+        let r = parse_input("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7")
+            .map(hash)
+            .sum::<u32>();
+        assert_eq!(r, 1320);
+    }
+
+    #[test]
+    fn test_focusing_power_of_lense_configuration() {
+        let steps = parse_steps("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7").unwrap();
+
+        assert_eq!(focusing_power_of_lense_configuration(steps), 145);
+    }
+
+    #[test]
+    fn test_parse_steps_errors() {
+        use utils::ParseError;
+
+        assert_eq!(
+            parse_steps("rn=1,cm,qp=3").unwrap_err(),
+            ParseError::new(1, 8, "`-` or `=`")
+        );
+        assert_eq!(
+            parse_steps("rn=1,cm=x").unwrap_err(),
+            ParseError::new(1, 9, "a focal length")
+        );
+        assert_eq!(
+            parse_steps("rn=1,=2").unwrap_err(),
+            ParseError::new(1, 6, "a label")
+        );
+    }
+}
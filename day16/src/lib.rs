@@ -0,0 +1,267 @@
+#![allow(unused)]
+
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<usize, utils::ParseError> {
+        Ok(tiles_energized(
+            parse_input(input)?,
+            Point::new(-1, 0),
+            Dir4::East,
+        ))
+    }
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(8125));
+    }
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<usize, utils::ParseError> {
+        let space = parse_input(input)?;
+
+        Ok(generate_starting_positions_directions(&space)
+            .into_iter()
+            .map(|(pos, direction)| tiles_energized(space.clone(), pos, direction))
+            .max()
+            .unwrap())
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(8489));
+    }
+}
+
+type Space = utils::Grid<(Cell, VisitedFromDirection)>;
+
+type Pos = Point;
+
+type VisitedFromUp = bool;
+type VisitedFromDown = bool;
+type VisitedFromLeft = bool;
+type VisitedFromRight = bool;
+
+type VisitedFromDirection = (
+    VisitedFromUp,
+    VisitedFromDown,
+    VisitedFromLeft,
+    VisitedFromRight,
+);
+
+#[derive(Debug, Clone, Copy)]
+enum Cell {
+    Empty,               /* . */
+    HorizontalSplitter,  /* - */
+    VerticalSplitter,    /* | */
+    DownwardSlopeMirror, /* \ */
+    UpwardSlopeMirror,   /* / */
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ();
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '-' => Ok(Self::HorizontalSplitter),
+            '|' => Ok(Self::VerticalSplitter),
+            '\\' => Ok(Self::DownwardSlopeMirror),
+            '/' => Ok(Self::UpwardSlopeMirror),
+            _ => Err(()),
+        }
+    }
+}
+
+enum NextDirections {
+    One(Dir4),
+    Split((Dir4, Dir4)),
+    Stop,
+}
+
+impl From<Dir4> for NextDirections {
+    fn from(value: Dir4) -> Self {
+        Self::One(value)
+    }
+}
+
+impl From<(Dir4, Dir4)> for NextDirections {
+    fn from(value: (Dir4, Dir4)) -> Self {
+        Self::Split(value)
+    }
+}
+
+fn next_directions(
+    direction: Dir4,
+    encountered_cell: Cell,
+    visited_from_direction: VisitedFromDirection,
+) -> NextDirections {
+    use Cell::*;
+    use Dir4::*;
+    use NextDirections::Stop;
+    match (direction, encountered_cell, visited_from_direction) {
+        (_, Empty, _) => direction.into(),
+
+        (West, _, (_, _, true, _)) => Stop,
+        (East, _, (_, _, _, true)) => Stop,
+        (North, _, (true, _, _, _)) => Stop,
+        (South, _, (_, true, _, _)) => Stop,
+
+        (East, HorizontalSplitter, (_, _, _, false)) => direction.into(),
+        (West, HorizontalSplitter, (_, _, false, _)) => direction.into(),
+        (North, HorizontalSplitter, (false, _, _, _)) => (West, East).into(),
+        (South, HorizontalSplitter, (_, false, _, _)) => (West, East).into(),
+
+        (North, VerticalSplitter, (false, _, _, _)) => direction.into(),
+        (South, VerticalSplitter, (_, false, _, _)) => direction.into(),
+        (West, VerticalSplitter, (_, _, false, _)) => (North, South).into(),
+        (East, VerticalSplitter, (_, _, _, false)) => (North, South).into(),
+
+        /* \ */
+        (West, DownwardSlopeMirror, (_, _, false, _)) => North.into(),
+        (East, DownwardSlopeMirror, (_, _, _, false)) => South.into(),
+        (North, DownwardSlopeMirror, (false, _, _, _)) => West.into(),
+        (South, DownwardSlopeMirror, (_, false, _, _)) => East.into(),
+
+        /* / */
+        (West, UpwardSlopeMirror, (_, _, false, _)) => South.into(),
+        (East, UpwardSlopeMirror, (_, _, _, false)) => North.into(),
+        (North, UpwardSlopeMirror, (false, _, _, _)) => East.into(),
+        (South, UpwardSlopeMirror, (_, false, _, _)) => West.into(),
+    }
+}
+
+fn visit_from_direction(direction: Dir4, visited_from_direction: &mut VisitedFromDirection) {
+    use Dir4::*;
+    match direction {
+        North => visited_from_direction.0 = true,
+        South => visited_from_direction.1 = true,
+        West => visited_from_direction.2 = true,
+        East => visited_from_direction.3 = true,
+    }
+}
+
+fn tiles_energized(space: Space, pos: Pos, direction: Dir4) -> usize {
+    fn explore_path(
+        (mut space, mut unique_nodes): (Space, UniqueNodes),
+        direction: Dir4,
+        pos: Pos,
+    ) -> (Space, UniqueNodes) {
+        let initial_direction = direction;
+        let pos = pos.step(direction);
+
+        match space.get(pos).copied() {
+            None => (space, unique_nodes),
+            Some((cell, visited_from_direction)) => {
+                unique_nodes.insert(pos);
+                match next_directions(direction, cell, visited_from_direction) {
+                    NextDirections::One(direction) => {
+                        let (_, visited_from_direction) = space.get_mut(pos).unwrap();
+                        visit_from_direction(initial_direction, visited_from_direction);
+                        explore_path((space, unique_nodes), direction, pos)
+                    }
+                    NextDirections::Split((d1, d2)) => {
+                        let (_, visited_from_direction) = space.get_mut(pos).unwrap();
+                        visit_from_direction(initial_direction, visited_from_direction);
+                        explore_path(explore_path((space, unique_nodes), d1, pos), d2, pos)
+                    }
+                    NextDirections::Stop => (space, unique_nodes),
+                }
+            }
+        }
+    }
+
+    type UniqueNodes = std::collections::HashSet<Pos>;
+    let unique_nodes = UniqueNodes::new();
+
+    let (_space, unique_nodes) = explore_path((space, unique_nodes), direction, pos);
+
+    unique_nodes.len()
+}
+
+fn generate_starting_positions_directions(space: &Space) -> Vec<(Pos, Dir4)> {
+    let width = space.width() as isize;
+    let height = space.height() as isize;
+
+    use Dir4::*;
+
+    let top = (0..width).map(|x| (Point::new(x, -1), South));
+    let bottom = (0..width).map(|x| (Point::new(x, height), North));
+
+    let left = (0..height).map(|y| (Point::new(-1, y), East));
+    let right = (0..height).map(|y| (Point::new(width, y), West));
+
+    top.clone()
+        .chain(bottom.clone())
+        .chain(left.clone())
+        .chain(right.clone())
+        .collect()
+}
+
+use utils::{Dir4, Point};
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+fn parse_input(input: &str) -> Result<Space, utils::ParseError> {
+    Space::parse(input, "`.`, `-`, `|`, `\\` or `/`", |c| {
+        Cell::try_from(c)
+            .ok()
+            .map(|cell| (cell, (false, false, false, false)))
+    })
+}
+
+pub struct Day16;
+
+impl utils::Solution for Day16 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_input_tiles_energized() {
+        let space = parse_input(test_input()).unwrap();
+        assert_eq!(tiles_energized(space, Point::new(-1, 0), Dir4::East), 46);
+    }
+
+    #[test]
+    fn test_test_input_2_tiles_energized() {
+        let space = parse_input(test_input_2()).unwrap();
+        assert_eq!(tiles_energized(space, Point::new(-1, 0), Dir4::East), 5);
+    }
+
+    #[cfg(test)]
+    fn test_input_2() -> &'static str {
+        r#".|\
+.\/"#
+    }
+
+    #[cfg(test)]
+    fn test_input() -> &'static str {
+        r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#
+    }
+}
@@ -0,0 +1,496 @@
+#![allow(unused)]
+
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<usize, utils::SolveError> {
+        heat_loss(input, &CrucibleRules::crucible())
+    }
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(1110));
+    }
+
+    #[test]
+    fn test_part_one_test_input() {
+        let space = parse_input(test_input()).unwrap();
+        let route = shortest_path(&space, &CrucibleRules::crucible()).unwrap();
+        assert_eq!(route.heat_loss, 102);
+        // as cheap as the route in the puzzle description, which goes down a block earlier
+        assert_eq!(
+            render_route(&space, &route),
+            "2>>34^>>>1323
+32v>>>35v>623
+325524565v>54
+3446585845v52
+4546657867v>6
+14385987984v4
+44578769877v6
+36378779796v>
+465496798688v
+456467998645v
+12246868655<v
+25465488877v5
+43226746555v>"
+        );
+    }
+}
+
+// of the best route, an error when there is none
+fn heat_loss(input: &str, rules: &CrucibleRules) -> Result<usize, utils::SolveError> {
+    let space = parse_input(input)?;
+    shortest_path(&space, rules)
+        .map(|route| route.heat_loss)
+        .ok_or_else(|| utils::SolveError::no_answer("the crucible can't reach the goal"))
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<usize, utils::SolveError> {
+        heat_loss(input, &CrucibleRules::ultra())
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        // an ultra crucible can't stop after a single block
+        assert_eq!(crate::part_one::part_one("12"), Ok(2));
+        assert_eq!(
+            part_two("12"),
+            Err(utils::SolveError::no_answer(
+                "the crucible can't reach the goal"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_part_two_test_input() {
+        let space = parse_input(test_input()).unwrap();
+        assert_eq!(
+            shortest_path(&space, &CrucibleRules::ultra())
+                .unwrap()
+                .heat_loss,
+            94
+        );
+    }
+
+    #[test]
+    fn test_part_two_test_input_2() {
+        let space = parse_input(test_input_2()).unwrap();
+        assert_eq!(
+            shortest_path(&space, &CrucibleRules::ultra())
+                .unwrap()
+                .heat_loss,
+            71
+        );
+    }
+}
+
+type HeatLoss = usize;
+type Cell = HeatLoss;
+type Space = utils::Grid<Cell>;
+
+type Pos = Point;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrucibleRules {
+    // blocks to move in a direction before turning or stopping
+    pub min_streak: usize,
+    // blocks to move in a direction at most
+    pub max_streak: usize,
+    // whether turning back counts as a turn
+    pub reverse: bool,
+    // the crucible may leave the start in
+    pub start_directions: Vec<Dir4>,
+    // the top left corner when `None`
+    pub start: Option<Pos>,
+    // the bottom right corner when `None`
+    pub goal: Option<Pos>,
+}
+
+impl CrucibleRules {
+    pub fn crucible() -> Self {
+        Self {
+            min_streak: 1,
+            max_streak: 3,
+            reverse: false,
+            start_directions: vec![Dir4::East, Dir4::South],
+            start: None,
+            goal: None,
+        }
+    }
+
+    pub fn ultra() -> Self {
+        Self {
+            min_streak: 4,
+            max_streak: 10,
+            ..Self::crucible()
+        }
+    }
+
+    // Applies `key = value` lines over these rules, see `config` for the keys.
+    // Blank lines and lines starting with `#` are skipped.
+    pub fn configure(mut self, config: &str) -> Result<Self, utils::ParseError> {
+        for setting in config::parse_settings(config)? {
+            match setting {
+                config::Setting::MinStreak(n) => self.min_streak = n,
+                config::Setting::MaxStreak(n) => self.max_streak = n,
+                config::Setting::Reverse(reverse) => self.reverse = reverse,
+                config::Setting::StartDirections(directions) => self.start_directions = directions,
+                config::Setting::Start(start) => self.start = Some(start),
+                config::Setting::Goal(goal) => self.goal = Some(goal),
+            }
+        }
+        Ok(self)
+    }
+}
+
+mod config {
+    use super::Pos;
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{self, char, space0},
+        combinator::{cut, map, value},
+        error::context,
+        multi::separated_list1,
+        sequence::{delimited, preceded, separated_pair},
+    };
+    use utils::parse::{parse_all, parse_lines, IResult};
+    use utils::Dir4;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Setting {
+        MinStreak(usize),
+        MaxStreak(usize),
+        Reverse(bool),
+        StartDirections(Vec<Dir4>),
+        Start(Pos),
+        Goal(Pos),
+    }
+
+    fn streak(input: &str) -> IResult<'_, usize> {
+        map(complete::u32, |n| n as usize)(input)
+    }
+
+    fn boolean(input: &str) -> IResult<'_, bool> {
+        context(
+            "`true` or `false`",
+            alt((value(true, tag("true")), value(false, tag("false")))),
+        )(input)
+    }
+
+    fn direction(input: &str) -> IResult<'_, Dir4> {
+        context(
+            "`north`, `east`, `south` or `west`",
+            alt((
+                value(Dir4::North, tag("north")),
+                value(Dir4::East, tag("east")),
+                value(Dir4::South, tag("south")),
+                value(Dir4::West, tag("west")),
+            )),
+        )(input)
+    }
+
+    fn directions(input: &str) -> IResult<'_, Vec<Dir4>> {
+        separated_list1(delimited(space0, char(','), space0), cut(direction))(input)
+    }
+
+    // `x,y` from the top left corner
+    fn position(input: &str) -> IResult<'_, Pos> {
+        context(
+            "a position like `3,5`",
+            map(
+                separated_pair(complete::i64, char(','), complete::i64),
+                |(x, y)| Pos::new(x as isize, y as isize),
+            ),
+        )(input)
+    }
+
+    fn setting(input: &str) -> IResult<'_, Setting> {
+        let (input, key) = context(
+            "`min_streak`, `max_streak`, `reverse`, `start_directions`, `start` or `goal`",
+            // `start_directions` first, `start` is a prefix of it
+            alt((
+                tag("min_streak"),
+                tag("max_streak"),
+                tag("reverse"),
+                tag("start_directions"),
+                tag("start"),
+                tag("goal"),
+            )),
+        )(input)?;
+        let (input, _) = delimited(space0, context("`=`", char('=')), space0)(input)?;
+
+        match key {
+            "min_streak" => map(streak, Setting::MinStreak)(input),
+            "max_streak" => map(streak, Setting::MaxStreak)(input),
+            "reverse" => map(boolean, Setting::Reverse)(input),
+            "start_directions" => map(directions, Setting::StartDirections)(input),
+            "start" => map(position, Setting::Start)(input),
+            "goal" => map(position, Setting::Goal)(input),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn parse_settings(config: &str) -> Result<Vec<Setting>, utils::ParseError> {
+        let settings = parse_lines(config, |line| {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                Ok(None)
+            } else {
+                parse_all(line, preceded(space0, setting)).map(Some)
+            }
+        })?;
+        Ok(settings.into_iter().flatten().collect())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    position: Pos,
+    direction: Dir4,
+    same_direction_streak: usize,
+}
+
+struct Crucible<'a> {
+    space: &'a Space,
+    rules: &'a CrucibleRules,
+    start: Pos,
+    goal: Pos,
+}
+
+impl SearchProblem for Crucible<'_> {
+    type State = State;
+
+    fn starts(&self) -> Vec<State> {
+        self.rules
+            .start_directions
+            .iter()
+            .map(|&direction| State {
+                position: self.start,
+                direction,
+                same_direction_streak: 0,
+            })
+            .collect()
+    }
+
+    fn neighbours(&self, state: &State) -> Vec<State> {
+        Dir4::ALL
+            .into_iter()
+            .filter(|direction| self.rules.reverse || *direction != state.direction.reverse())
+            .filter_map(|direction| {
+                let same_direction_streak = if direction == state.direction {
+                    state.same_direction_streak + 1
+                // at the start only the start direction is allowed
+                } else if state.same_direction_streak >= self.rules.min_streak.max(1) {
+                    1
+                } else {
+                    return None;
+                };
+
+                (same_direction_streak <= self.rules.max_streak).then_some(State {
+                    position: self.space.step(state.position, direction)?,
+                    direction,
+                    same_direction_streak,
+                })
+            })
+            .collect()
+    }
+
+    fn cost(&self, _: &State, to: &State) -> usize {
+        *self.space.get(to.position).unwrap()
+    }
+
+    fn is_goal(&self, state: &State) -> bool {
+        state.position == self.goal && state.same_direction_streak >= self.rules.min_streak
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub heat_loss: HeatLoss,
+    // every block entered after the start, with the direction it was entered in
+    pub steps: Vec<(Pos, Dir4)>,
+}
+
+// `None` when the goal can't be reached under `rules`
+pub fn shortest_path(space: &Space, rules: &CrucibleRules) -> Option<Route> {
+    let crucible = Crucible {
+        space,
+        rules,
+        start: rules.start.unwrap_or(Point::ORIGIN),
+        goal: rules.goal.unwrap_or(Point::new(
+            space.width() as isize - 1,
+            space.height() as isize - 1,
+        )),
+    };
+
+    if !space.contains(crucible.start) || !space.contains(crucible.goal) {
+        return None;
+    }
+
+    // every block left costs at least the cheapest one
+    let cheapest = space.iter().map(|(_, heat_loss)| *heat_loss).min()?;
+    search::astar(&crucible, |state| {
+        state.position.manhattan(crucible.goal) * cheapest
+    })
+    .map(|path| Route {
+        heat_loss: path.cost,
+        steps: path
+            .states
+            .into_iter()
+            .skip(1)
+            .map(|state| (state.position, state.direction))
+            .collect(),
+    })
+}
+
+// the heat map with the route drawn over it, like in the puzzle description
+pub fn render_route(space: &Space, route: &Route) -> String {
+    let mut map = space.map(|heat_loss| char::from_digit(*heat_loss as u32, 10).unwrap());
+    for (position, direction) in &route.steps {
+        *map.get_mut(*position).unwrap() = match direction {
+            Dir4::North => '^',
+            Dir4::East => '>',
+            Dir4::South => 'v',
+            Dir4::West => '<',
+        };
+    }
+    map.to_string()
+}
+
+pub fn parse_input(v: &str) -> Result<Space, utils::ParseError> {
+    Space::parse(v, "a digit", |c| c.to_digit(10).map(|v| v as Cell))
+}
+
+use utils::search::{self, SearchProblem};
+use utils::{Dir4, Point};
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+fn test_input() -> &'static str {
+    "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"
+}
+
+fn test_input_2() -> &'static str {
+    "111111111111
+999999999991
+999999999991
+999999999991
+999999999991"
+}
+
+pub struct Day17;
+
+impl utils::Solution for Day17 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        part_one::part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        part_two::part_two(input).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configure() {
+        let rules = CrucibleRules::crucible()
+            .configure(
+                "# ultra crucible, from the right
+min_streak = 4
+max_streak=10
+
+start_directions = west, south
+start = 12,0
+  goal = 0,12
+reverse = true",
+            )
+            .unwrap();
+
+        assert_eq!(
+            rules,
+            CrucibleRules {
+                reverse: true,
+                start_directions: vec![Dir4::West, Dir4::South],
+                start: Some(Point::new(12, 0)),
+                goal: Some(Point::new(0, 12)),
+                ..CrucibleRules::ultra()
+            }
+        );
+    }
+
+    #[test]
+    fn test_configure_errors() {
+        let configure = |config| CrucibleRules::crucible().configure(config);
+        assert_eq!(
+            configure("min_streak = 1\nmax = 3"),
+            Err(utils::ParseError::new(
+                2,
+                1,
+                "`min_streak`, `max_streak`, `reverse`, `start_directions`, `start` or `goal`"
+            ))
+        );
+        assert_eq!(
+            configure("start_directions = east, up"),
+            Err(utils::ParseError::new(
+                1,
+                26,
+                "`north`, `east`, `south` or `west`"
+            ))
+        );
+        assert_eq!(
+            configure("goal 3,3"),
+            Err(utils::ParseError::new(1, 6, "`=`"))
+        );
+    }
+
+    #[test]
+    fn test_start_and_goal() {
+        let space = parse_input(test_input_2()).unwrap();
+        let rules = CrucibleRules::crucible()
+            .configure("start = 3,0\ngoal = 0,0\nstart_directions = west")
+            .unwrap();
+        assert_eq!(shortest_path(&space, &rules).unwrap().heat_loss, 3);
+
+        let rules = rules.configure("goal = 12,0").unwrap();
+        assert_eq!(shortest_path(&space, &rules), None);
+    }
+
+    #[test]
+    fn test_reverse() {
+        // the only way back to the left is turning around
+        let space = parse_input("1111").unwrap();
+        let rules = CrucibleRules::crucible()
+            .configure("start = 1,0\ngoal = 0,0\nstart_directions = east")
+            .unwrap();
+        assert_eq!(shortest_path(&space, &rules), None);
+
+        let rules = rules.configure("reverse = true").unwrap();
+        let route = shortest_path(&space, &rules).unwrap();
+        assert_eq!(route.heat_loss, 3);
+        assert_eq!(render_route(&space, &route), "<<>1");
+    }
+}
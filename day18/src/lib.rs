@@ -0,0 +1,189 @@
+#![allow(unused)]
+
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<usize, utils::ParseError> {
+        let steps = utils::parse::parse_lines(input, parse::process_line)?;
+
+        let (perimeter, ring) = walk_steps(steps);
+
+        let total_area = total_area(&ring);
+
+        Ok(total_area + perimeter / 2 + 1)
+    }
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(62365));
+    }
+}
+
+pub struct Day18;
+
+impl utils::Solution for Day18 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<usize, utils::ParseError> {
+        let steps = utils::parse::parse_lines(input, |l| parse::process_line(l).map(Step::fix))?;
+        let (perimeter, ring) = walk_steps(steps);
+        let total_area = total_area(&ring);
+        Ok(total_area + perimeter / 2 + 1)
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(159485361249806));
+    }
+}
+
+fn total_area(ring: &[Point]) -> usize {
+    ring.iter()
+        .chain(std::iter::once(&ring[0]))
+        .collect::<Vec<_>>()
+        .as_slice()
+        .windows(2)
+        // https://en.wikipedia.org/wiki/Shoelace_formula, see Example
+        .map(|window| {
+            let (l, r) = (window[0], window[1]);
+
+            (l.x * r.y) - (r.x * l.y)
+        })
+        .sum::<isize>() as usize
+        / 2
+}
+
+type Steps = Vec<Step>;
+type Perimeter = usize;
+
+fn walk_steps(steps: Steps) -> (Perimeter, Vec<Point>) {
+    let mut contour = vec![Point::ORIGIN];
+    let mut perimeter = 0;
+
+    for step in steps {
+        perimeter += step.n as usize;
+        contour.push(
+            contour
+                .last()
+                .unwrap()
+                .step_n(step.direction, step.n as isize),
+        );
+    }
+
+    contour.remove(contour.len() - 1);
+
+    (perimeter, contour)
+}
+
+fn direction(value: char) -> Dir4 {
+    match value {
+        'R' | '0' => Dir4::East,
+        'L' | '2' => Dir4::West,
+        'U' | '3' => Dir4::North,
+        'D' | '1' => Dir4::South,
+        _ => unreachable!(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Step {
+    direction: Dir4,
+    n: u32,
+    color: String,
+}
+
+#[allow(unused)]
+fn display_ring(ring: &[Point]) {
+    let existing = ring.iter().collect::<std::collections::HashSet<_>>();
+
+    let max_y = ring.iter().map(|p| p.y).max().unwrap();
+    let max_x = ring.iter().map(|p| p.x).max().unwrap();
+
+    for j in (0..max_y + 1) {
+        for i in (0..max_x + 1) {
+            if existing.contains(&Point::new(i, j)) {
+                print!("#");
+            } else {
+                print!(".");
+            }
+        }
+        println!()
+    }
+}
+
+impl Step {
+    pub fn fix(mut self) -> Self {
+        self.n = u32::from_str_radix(&self.color[0..5], 16).unwrap();
+        self.direction = direction(self.color.chars().last().unwrap());
+        self
+    }
+}
+
+use utils::{Dir4, Point};
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+mod parse {
+    use super::{direction, Step};
+    use nom::{
+        bytes::complete::{tag, take_while_m_n},
+        character::complete::{self, one_of, space1},
+        combinator::{map, recognize},
+        error::context,
+        sequence::pair,
+    };
+    use utils::parse::{parse_all, IResult};
+    use utils::Dir4;
+
+    fn parse_direction(input: &str) -> IResult<'_, Dir4> {
+        context("`R`, `L`, `U` or `D`", map(one_of("RLUD"), direction))(input)
+    }
+
+    // five hex digits of distance followed by a direction digit
+    fn parse_color(input: &str) -> IResult<'_, &str> {
+        context(
+            "a color like `70c710`",
+            recognize(pair(
+                take_while_m_n(5, 5, |c: char| c.is_ascii_hexdigit()),
+                one_of("0123"),
+            )),
+        )(input)
+    }
+
+    fn parse_line(input: &str) -> IResult<'_, Step> {
+        let (input, direction) = parse_direction(input)?;
+        let (input, _) = space1(input)?;
+        let (input, n) = complete::u32(input)?;
+        let (input, _) = space1(input)?;
+        let (input, _) = context("`(#`", tag("(#"))(input)?;
+        let (input, color) = parse_color(input)?;
+        let (input, _) = context("`)`", tag(")"))(input)?;
+        Ok((
+            input,
+            Step {
+                direction,
+                n,
+                color: color.into(),
+            },
+        ))
+    }
+
+    pub fn process_line(input: &str) -> Result<Step, utils::ParseError> {
+        parse_all(input, parse_line)
+    }
+}
@@ -0,0 +1,409 @@
+#![allow(unused)]
+
+pub struct Day19;
+
+impl utils::Solution for Day19 {
+    type Answer = Num;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        let (workflows, parts) = parse::parse_input(input)?;
+        part_one(workflows, parts)
+    }
+
+    // not solved yet
+    fn part_two(_input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(None)
+    }
+}
+
+// 333263
+fn part_one(workflows: Workflows, parts: Parts) -> Result<Num, utils::SolveError> {
+    // an error when the part comes back to a workflow it went through
+    fn part_accepted(part: Part, workflows: &Workflows) -> Result<bool, utils::SolveError> {
+        let mut workflow = workflows.get("in").unwrap();
+        let mut visited = std::collections::HashSet::from(["in"]);
+
+        loop {
+            match workflow.process_part(part) {
+                Next::Accept => return Ok(true),
+                Next::Reject => return Ok(false),
+                Next::Send(name) => {
+                    workflow = workflows.get(&name).unwrap();
+                    if !visited.insert(workflow.name.as_str()) {
+                        return Err(utils::SolveError::no_answer(format!(
+                            "a part comes back to workflow `{name}`"
+                        )));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut sum = 0;
+    for part in parts {
+        if part_accepted(part, &workflows)? {
+            let Part { x, m, a, s } = part;
+            sum += x + m + a + s;
+        }
+    }
+    Ok(sum)
+}
+
+pub type Num = usize;
+
+#[derive(Debug, Clone, Copy)]
+pub enum RatingLabel {
+    X,
+    M,
+    A,
+    S,
+}
+
+pub type WorkflowName = String;
+
+#[derive(Debug)]
+pub struct Workflow {
+    name: WorkflowName,
+    ruleset: Ruleset,
+}
+
+impl Workflow {
+    fn process_part(&self, part: Part) -> Next {
+        self.ruleset.process_part(part)
+    }
+}
+
+pub type Workflows = std::collections::HashMap<WorkflowName, Workflow>;
+
+#[derive(Debug, Clone)]
+pub enum Next {
+    Accept,
+    Reject,
+    Send(WorkflowName),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Condition {
+    Greater(Num),
+    Less(Num),
+}
+
+#[derive(Debug)]
+pub enum Rule {
+    Comparative {
+        condition: Condition,
+        next: Next,
+        rating_label: RatingLabel,
+    },
+    Accept,
+    Reject,
+    Send(WorkflowName),
+}
+
+impl Rule {
+    pub fn process_part(&self, part: Part) -> Option<Next> {
+        match self {
+            Self::Accept => Some(Next::Accept),
+            Self::Reject => Some(Next::Reject),
+            Self::Send(name) => Some(Next::Send(name.clone())),
+            Self::Comparative {
+                condition,
+                next,
+                rating_label,
+            } => {
+                let value = part.label(*rating_label);
+
+                let applies = match *condition {
+                    Condition::Greater(than) => value > than,
+                    Condition::Less(than) => value < than,
+                };
+
+                if applies {
+                    Some(next.clone())
+                } else {
+                    // the rule does not apply, I don't know what to do,
+                    // move to the next
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Ruleset(Vec<Rule>);
+
+impl std::ops::Deref for Ruleset {
+    type Target = Vec<Rule>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Ruleset {
+    pub fn process_part(&self, part: Part) -> Next {
+        for rule in self.iter() {
+            match rule.process_part(part) {
+                None => continue,
+                Some(next) => return next,
+            }
+        }
+        unreachable!()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Part {
+    x: Num,
+    m: Num,
+    a: Num,
+    s: Num,
+}
+
+impl Part {
+    pub fn label(self, label: RatingLabel) -> Num {
+        let Part { x, m, a, s } = self;
+        use RatingLabel::*;
+        match label {
+            X => x,
+            M => m,
+            A => a,
+            S => s,
+        }
+    }
+}
+
+pub type Parts = Vec<Part>;
+mod parse {
+    use super::*;
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{self, alpha1, newline, one_of},
+        combinator::{consumed, cut, map},
+        error::context,
+        multi::separated_list1,
+        Offset,
+    };
+    use std::collections::HashSet;
+    use utils::parse::{parse_all, IResult};
+
+    fn parse_next(input: &str) -> IResult<'_, Next> {
+        let (input, name) = context("`A`, `R` or a workflow name", alpha1)(input)?;
+        let next = match name {
+            "A" => Next::Accept,
+            "R" => Next::Reject,
+            workflow_name => Next::Send(workflow_name.into()),
+        };
+        Ok((input, next))
+    }
+
+    fn parse_comparative(input: &str) -> IResult<'_, Rule> {
+        let (input, label) = one_of("xmas")(input)?;
+        let rating_label = match label {
+            'x' => RatingLabel::X,
+            'm' => RatingLabel::M,
+            'a' => RatingLabel::A,
+            's' => RatingLabel::S,
+            _ => unreachable!(),
+        };
+
+        let (input, sign) = one_of("<>")(input)?;
+        let (input, num) = cut(complete::u32)(input)?;
+        let condition = match sign {
+            '<' => Condition::Less(num as _),
+            '>' => Condition::Greater(num as _),
+            _ => unreachable!(),
+        };
+
+        let (input, _) = cut(context("`:`", tag(":")))(input)?;
+        let (input, next) = cut(parse_next)(input)?;
+
+        Ok((
+            input,
+            Rule::Comparative {
+                condition,
+                next,
+                rating_label,
+            },
+        ))
+    }
+
+    fn parse_rule(input: &str) -> IResult<'_, Rule> {
+        alt((
+            parse_comparative,
+            map(parse_next, |next| match next {
+                Next::Accept => Rule::Accept,
+                Next::Reject => Rule::Reject,
+                Next::Send(name) => Rule::Send(name),
+            }),
+        ))(input)
+    }
+
+    // with the text of every rule, to point at them
+    fn parse_workflow(input: &str) -> IResult<'_, (Workflow, Vec<&str>)> {
+        let (input, name) = context("a workflow name", alpha1)(input)?;
+        let (input, _) = context("`{`", tag("{"))(input)?;
+        let (input, rules) = cut(separated_list1(tag(","), consumed(parse_rule)))(input)?;
+        let (input, _) = cut(context("`}`", tag("}")))(input)?;
+
+        let (texts, rules) = rules.into_iter().unzip();
+        Ok((
+            input,
+            (
+                Workflow {
+                    name: name.into(),
+                    ruleset: Ruleset(rules),
+                },
+                texts,
+            ),
+        ))
+    }
+
+    fn parse_rating<'a>(input: &'a str, label: &'static str) -> IResult<'a, Num> {
+        let (input, _) = context(label, tag(label.trim_matches('`')))(input)?;
+        let (input, rating) = complete::u32(input)?;
+        Ok((input, rating as _))
+    }
+
+    fn parse_part(input: &str) -> IResult<'_, Part> {
+        let (input, x) = parse_rating(input, "`{x=`")?;
+        cut(move |input| {
+            let (input, m) = parse_rating(input, "`,m=`")?;
+            let (input, a) = parse_rating(input, "`,a=`")?;
+            let (input, s) = parse_rating(input, "`,s=`")?;
+            let (input, _) = context("`}`", tag("}"))(input)?;
+            Ok((input, Part { x, m, a, s }))
+        })(input)
+    }
+
+    // rejects workflows that can't process every part: without a last rule to fall back on,
+    // sending parts to undefined workflows, or missing the `in` one to start with
+    pub fn parse_input(input: &str) -> Result<(Workflows, Parts), utils::ParseError> {
+        let (workflows, parts) = parse_all(input, |input| {
+            let (input, workflows) = separated_list1(newline, parse_workflow)(input)?;
+            let (input, _) = newline(input)?;
+            let (input, _) = newline(input)?;
+            let (input, parts) = separated_list1(newline, parse_part)(input)?;
+
+            Ok((input, (workflows, parts)))
+        })?;
+
+        let error =
+            |at: &str, expected| utils::ParseError::at(input, &input[input.offset(at)..], expected);
+
+        let defined = workflows
+            .iter()
+            .map(|(workflow, _)| workflow.name.as_str())
+            .collect::<HashSet<_>>();
+
+        for (workflow, texts) in &workflows {
+            if let (Some(Rule::Comparative { .. }), Some(text)) =
+                (workflow.ruleset.last(), texts.last())
+            {
+                return Err(error(
+                    text,
+                    "a rule without a condition to end the workflow",
+                ));
+            }
+
+            for (rule, text) in workflow.ruleset.iter().zip(texts) {
+                let (Rule::Send(target)
+                | Rule::Comparative {
+                    next: Next::Send(target),
+                    ..
+                }) = rule
+                else {
+                    continue;
+                };
+                if !defined.contains(target.as_str()) {
+                    // the target ends the rule
+                    return Err(error(
+                        &text[text.len() - target.len()..],
+                        "a defined workflow",
+                    ));
+                }
+            }
+        }
+
+        if !defined.contains("in") {
+            return Err(utils::ParseError::new(1, 1, "a workflow named `in`"));
+        }
+
+        let workflows = workflows
+            .into_iter()
+            .map(|(workflow, _)| (workflow.name.clone(), workflow))
+            .collect();
+
+        Ok((workflows, parts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Solution;
+
+    #[test]
+    fn test_part_one() {
+        let input = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+        assert_eq!(Day19::part_one(input), Ok(19114));
+    }
+
+    #[test]
+    fn test_invalid_workflows() {
+        let error = |input: &str| parse::parse_input(input).err();
+
+        assert_eq!(
+            error("in{a<5:A}\n\n{x=1,m=2,a=9,s=4}"),
+            Some(utils::ParseError::new(
+                1,
+                4,
+                "a rule without a condition to end the workflow"
+            ))
+        );
+        assert_eq!(
+            error("in{a<5:px,R}\n\n{x=1,m=2,a=9,s=4}"),
+            Some(utils::ParseError::new(1, 8, "a defined workflow"))
+        );
+        assert_eq!(
+            error("in{a<5:A,qs}\n\n{x=1,m=2,a=9,s=4}"),
+            Some(utils::ParseError::new(1, 10, "a defined workflow"))
+        );
+        assert_eq!(
+            error("px{a<5:A,R}\n\n{x=1,m=2,a=9,s=4}"),
+            Some(utils::ParseError::new(1, 1, "a workflow named `in`"))
+        );
+
+        // parses, but the part goes round forever
+        assert_eq!(
+            <Day19 as utils::Solution>::part_one("in{px}\npx{in}\n\n{x=1,m=2,a=3,s=4}"),
+            Err(utils::SolveError::no_answer(
+                "a part comes back to workflow `in`"
+            ))
+        );
+        // a cycle no part follows is fine
+        assert_eq!(
+            <Day19 as utils::Solution>::part_one("in{x>5:px,A}\npx{x<3:in,R}\n\n{x=9,m=2,a=3,s=4}"),
+            Ok(0)
+        );
+    }
+}
@@ -0,0 +1,208 @@
+mod part_one {
+    use super::parse::*;
+    use utils::{parse::parse_lines, ParseError};
+
+    pub fn part_one(input: &str) -> Result<u32, ParseError> {
+        Ok(parse_lines(input, process_line)?
+            .into_iter()
+            .map(id_if_possible)
+            .sum())
+    }
+
+    pub fn id_if_possible((id, games): (Id, Games)) -> u32 {
+        let possible = games
+            .into_iter()
+            .map(|game| {
+                game.into_iter().all(|CountColor { count, color }| {
+                    (color == "red" && count <= 12)
+                        || (color == "green" && count <= 13)
+                        || (color == "blue" && count <= 14)
+                })
+            })
+            .all(std::convert::identity);
+
+        if possible {
+            id
+        } else {
+            0
+        }
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&super::input()), Ok(2617));
+    }
+}
+
+mod part_two {
+    use super::parse::*;
+    use utils::{parse::parse_lines, ParseError};
+
+    pub fn part_two(input: &str) -> Result<u32, ParseError> {
+        Ok(parse_lines(input, process_line)?
+            .into_iter()
+            .map(mult_pow)
+            .sum())
+    }
+
+    pub fn mult_pow((_id, games): (Id, Games)) -> u32 {
+        let mut color_count = std::collections::HashMap::<_, u32>::new();
+
+        for game in games {
+            for CountColor { count, color } in game {
+                color_count
+                    .entry(color)
+                    .and_modify(|v| {
+                        *v = (*v).max(count);
+                    })
+                    .or_insert(count);
+            }
+        }
+
+        color_count.values().fold(1, |acc, x| acc * x)
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&super::input()), Ok(59795));
+    }
+}
+
+pub struct Day2;
+
+impl utils::Solution for Day2 {
+    type Answer = u32;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+mod parse {
+    use nom::{
+        branch::alt, bytes::complete::tag, character::complete, combinator::cut, error::context,
+        multi::separated_list1,
+    };
+    use utils::parse::{parse_all, IResult};
+    use utils::ParseError;
+
+    #[derive(Debug, PartialEq)]
+    pub struct CountColor<'a> {
+        pub count: u32,
+        pub color: &'a str,
+    }
+
+    pub type Id = u32;
+    pub type Game<'a> = Vec<CountColor<'a>>;
+    pub type Games<'a> = Vec<Game<'a>>;
+
+    fn parse_count_color(input: &str) -> IResult<'_, CountColor<'_>> {
+        let (input, count) = complete::u32(input)?;
+        let (input, _) = context("` `", tag(" "))(input)?;
+        let (input, color) = cut(context(
+            "`red`, `green` or `blue`",
+            alt((tag("red"), tag("green"), tag("blue"))),
+        ))(input)?;
+        Ok((input, CountColor { count, color }))
+    }
+
+    fn parse_game(input: &str) -> IResult<'_, Game<'_>> {
+        let (input, result) = separated_list1(tag(", "), parse_count_color)(input)?;
+        Ok((input, result))
+    }
+
+    fn parse_games(input: &str) -> IResult<'_, Games<'_>> {
+        let (input, result) = separated_list1(tag("; "), parse_game)(input)?;
+        Ok((input, result))
+    }
+
+    fn parse_line(input: &str) -> IResult<'_, (Id, Games<'_>)> {
+        let (input, _) = context("`Game `", tag("Game "))(input)?;
+        let (input, id) = complete::u32(input)?;
+        let (input, _) = context("`: `", tag(": "))(input)?;
+        let (input, game) = parse_games(input)?;
+        Ok((input, (id, game)))
+    }
+
+    pub fn process_line(input: &str) -> Result<(Id, Games<'_>), ParseError> {
+        parse_all(input, parse_line)
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_process_line_errors() {
+        assert_eq!(
+            process_line("Game 1: 3 red, 1 purple"),
+            Err(ParseError::new(1, 18, "`red`, `green` or `blue`"))
+        );
+        assert_eq!(
+            process_line("Game one: 3 red"),
+            Err(ParseError::new(1, 6, "a number"))
+        );
+        assert_eq!(
+            process_line("Game 1: 3 red 1 blue"),
+            Err(ParseError::new(1, 15, "end of input"))
+        );
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_parse_line() {
+        let (remaining, (id, games)) =
+            parse_line("Game 22: 3 red, 1 blue; 3 green, 1 red, 1 blue; 7 green, 2 blue").unwrap();
+
+        assert!(remaining.is_empty());
+
+        assert_eq!(id, 22);
+        assert_eq!(
+            games,
+            vec![
+                vec![
+                    CountColor {
+                        count: 3,
+                        color: "red",
+                    },
+                    CountColor {
+                        count: 1,
+                        color: "blue",
+                    },
+                ],
+                vec![
+                    CountColor {
+                        count: 3,
+                        color: "green",
+                    },
+                    CountColor {
+                        count: 1,
+                        color: "red",
+                    },
+                    CountColor {
+                        count: 1,
+                        color: "blue",
+                    },
+                ],
+                vec![
+                    CountColor {
+                        count: 7,
+                        color: "green",
+                    },
+                    CountColor {
+                        count: 2,
+                        color: "blue",
+                    },
+                ],
+            ]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+mod part_one {
+    use super::imports::*;
+
+    pub fn part_one(input: &str) -> Result<u32, ParseError> {
+        Ok(calc(parse_input(input)?))
+    }
+
+    fn calc(space: Space) -> u32 {
+        let mut total = 0;
+
+        for (y, row) in space.rows().enumerate() {
+            for (x, num) in extract_nums(row) {
+                let touches_symbol = (x..x + num_len(num)).any(|x| {
+                    space
+                        .neighbours8(utils::Point::new(x as isize, y as isize))
+                        .any(|(_, cell)| cell.is_symbol())
+                });
+
+                if touches_symbol {
+                    total += num;
+                }
+            }
+        }
+
+        total
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&super::input()), Ok(535351));
+    }
+}
+
+mod part_two {
+    use super::imports::*;
+
+    pub fn part_two(input: &str) -> Result<u32, ParseError> {
+        Ok(calc(parse_input(input)?))
+    }
+
+    fn calc(space: Space) -> u32 {
+        let mut total = 0;
+
+        for (y, row) in space.rows().enumerate() {
+            for x in extract_syms(row) {
+                // numbers of the rows above, at and below the symbol, one of their digits
+                // is in the symbol column or next to it
+                let matched_nums = (y.saturating_sub(1)..=y + 1)
+                    .filter(|y| *y < space.height())
+                    .flat_map(|y| extract_nums(space.row(y)))
+                    .filter(|(n_x, num)| (n_x.saturating_sub(1)..=n_x + num_len(*num)).contains(&x))
+                    .collect::<Vec<_>>();
+
+                if matched_nums.len() == 2 {
+                    total += matched_nums
+                        .into_iter()
+                        .map(|(_, num)| num)
+                        .product::<u32>()
+                }
+            }
+        }
+
+        total
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&super::input()), Ok(87287096));
+    }
+}
+
+mod imports {
+    pub use utils::ParseError;
+
+    pub type Space = utils::Grid<Cell>;
+    pub type Row = [Cell];
+
+    #[derive(strum::EnumIs, Clone)]
+    pub enum Cell {
+        Number(u32),
+        Symbol,
+        Empty,
+    }
+
+    pub fn parse_input(input: &str) -> Result<Space, ParseError> {
+        Space::parse(input, "a digit, `.` or a symbol", |c| match c {
+            c if c.is_ascii_digit() => c.to_digit(10).map(Cell::Number),
+            '.' => Some(Cell::Empty),
+            c if c.is_ascii_graphic() => Some(Cell::Symbol),
+            _ => None,
+        })
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_parse_input_errors() {
+        assert_eq!(
+            parse_input("467..114..\n...*.. ...").err(),
+            Some(ParseError::new(2, 7, "a digit, `.` or a symbol"))
+        );
+        assert_eq!(
+            parse_input("467..114..\n...*").err(),
+            Some(ParseError::new(2, 5, "a row of 10 cells"))
+        );
+    }
+
+    pub fn num_len(num: u32) -> usize {
+        (num.checked_ilog10().unwrap_or(0) + 1) as usize
+    }
+
+    pub fn extract_syms(row: &Row) -> Vec<usize> {
+        row.into_iter()
+            .enumerate()
+            .filter(|(_i, cell)| cell.is_symbol())
+            .map(|(i, _cell)| i)
+            .collect()
+    }
+
+    pub fn extract_nums(row: &Row) -> Vec<(usize, u32)> {
+        let mut numbers = vec![];
+
+        let mut finished_num = true;
+        for (i, cell) in row.iter().enumerate() {
+            if !cell.is_number() {
+                finished_num = true
+            }
+
+            if let Cell::Number(num) = cell {
+                if finished_num {
+                    numbers.push((i, *num));
+                    finished_num = false;
+                } else {
+                    let (_i, last) = numbers.last_mut().unwrap();
+                    *last = *last * 10 + num;
+                }
+            }
+        }
+
+        numbers
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+pub struct Day3;
+
+impl utils::Solution for Day3 {
+    type Answer = u32;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
@@ -0,0 +1,164 @@
+mod part_one {
+    use super::parse::{process_line, Card};
+    use utils::{parse::parse_lines, ParseError};
+
+    pub fn part_one(input: &str) -> Result<u32, ParseError> {
+        Ok(parse_lines(input, process_line)?
+            .into_iter()
+            .map(calc_points)
+            .sum::<u32>())
+    }
+
+    fn calc_points(card: Card) -> u32 {
+        let count = card.winning_count();
+
+        if count == 0 || count == 1 {
+            count
+        } else {
+            2u32.pow(count - 1)
+        }
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&super::input()), Ok(18653));
+    }
+}
+
+mod part_two {
+    use super::parse::{process_line, Card};
+    use utils::{parse::parse_lines, ParseError};
+
+    pub fn part_two(input: &str) -> Result<u32, ParseError> {
+        Ok(calc(parse_lines(input, process_line)?))
+    }
+
+    fn calc(cards: Vec<Card>) -> u32 {
+        let mut copies = std::collections::HashMap::<u32, u32>::new();
+
+        for card in cards.iter() {
+            let id = card.id;
+
+            let winning_count = card.winning_count();
+
+            let inc = *copies.get(&id).unwrap_or(&0) + 1;
+            for id in (id + 1)..(id + 1 + winning_count) {
+                copies.entry(id).and_modify(|v| *v += inc).or_insert(inc);
+            }
+        }
+
+        cards.len() as u32 + copies.values().sum::<u32>()
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&super::input()), Ok(5921508));
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+mod parse {
+    use nom::{bytes::complete::tag, character::complete, error::context, multi::separated_list1};
+    use utils::parse::{parse_all, IResult};
+    use utils::ParseError;
+
+    type Id = u32;
+
+    #[derive(Debug)]
+    pub struct Card {
+        pub id: Id,
+        pub winning_nums: WinningNums,
+        pub given_nums: GivenNums,
+    }
+
+    impl Card {
+        pub fn winning_count(&self) -> u32 {
+            self.given_nums.intersection(&self.winning_nums).count() as _
+        }
+    }
+
+    type Nums = std::collections::HashSet<u32>;
+    type WinningNums = Nums;
+    type GivenNums = Nums;
+
+    fn parse_nums(input: &str) -> IResult<'_, Nums> {
+        let (input, result) = separated_list1(complete::space1, complete::u32)(input)?;
+        Ok((input, result.into_iter().collect()))
+    }
+
+    fn parse_num_sets(input: &str) -> IResult<'_, (WinningNums, GivenNums)> {
+        let (input, winning) = parse_nums(input)?;
+        let (input, _) = context("` | `", tag(" | "))(input)?;
+        let (input, _) = complete::space0(input)?;
+        let (input, given) = parse_nums(input)?;
+        Ok((input, (winning, given)))
+    }
+
+    fn parse_line(input: &str) -> IResult<'_, Card> {
+        let (input, _) = context("`Card`", tag("Card"))(input)?;
+        let (input, _) = complete::space1(input)?;
+        let (input, id) = complete::u32(input)?;
+        let (input, _) = context("`:`", tag(":"))(input)?;
+        let (input, _) = complete::space1(input)?;
+        let (input, (winning_nums, given_nums)) = parse_num_sets(input)?;
+        Ok((
+            input,
+            Card {
+                id,
+                winning_nums,
+                given_nums,
+            },
+        ))
+    }
+
+    pub fn process_line(input: &str) -> Result<Card, ParseError> {
+        parse_all(input, parse_line)
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_process_line_errors() {
+        assert_eq!(
+            process_line("Card 3:  1 21 | 69 x").err(),
+            Some(ParseError::new(1, 20, "end of input"))
+        );
+        assert_eq!(
+            process_line("Card 3:  1 21 / 69 82").err(),
+            Some(ParseError::new(1, 14, "` | `"))
+        );
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_parse_line() {
+        let (remaining, card) =
+            parse_line("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1".into()).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(card.id, 3);
+        assert_eq!(card.winning_nums, [1, 21, 53, 59, 44].into_iter().collect());
+        assert_eq!(
+            card.given_nums,
+            [69, 82, 63, 72, 16, 21, 14, 1].into_iter().collect()
+        );
+    }
+}
+
+pub struct Day4;
+
+impl utils::Solution for Day4 {
+    type Answer = u32;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
@@ -0,0 +1,776 @@
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<i64, utils::SolveError> {
+        let parsed = parse::parse_input(input)?;
+        let table = parsed.query("seed", "location")?;
+
+        Ok(parsed
+            .seeds
+            .iter()
+            .map(|seed| table.source_to_dest(*seed))
+            .min()
+            .unwrap())
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(993500720));
+    }
+}
+
+// in part two the seeds come in pairs of start and length,
+// `parse::parse_input` checks that each pair is a non-empty range that fits in an i64
+fn seed_ranges(seeds: &[i64]) -> Vec<std::ops::Range<i64>> {
+    seeds
+        .chunks_exact(2)
+        .map(|chunk| {
+            let (start, range) = (chunk[0], chunk[1]);
+            start..start + range
+        })
+        .collect()
+}
+
+pub struct Day5;
+
+impl utils::Solution for Day5 {
+    type Answer = i64;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        part_one::part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        part_two::part_two(input).map(Some)
+    }
+}
+
+pub mod parse {
+    use std::ops::Range;
+
+    use std::collections::{HashMap, HashSet, VecDeque};
+
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{self, alpha1},
+        error::context,
+        multi::separated_list1,
+        sequence::{pair, separated_pair, terminated},
+    };
+    use utils::parse::{parse_all, IResult};
+
+    type Dest = i64;
+    type Source = i64;
+
+    #[derive(Debug, Clone)]
+    pub struct Mapping {
+        pub dest: Dest,
+        pub source: Source,
+        pub range: i64,
+    }
+
+    impl Mapping {
+        pub fn source_to_dest(&self, source: Source) -> Option<Dest> {
+            if self.source_range().contains(&source) {
+                Some(source + (self.dest - self.source))
+            } else {
+                None
+            }
+        }
+
+        pub fn dest_to_source(&self, dest: Dest) -> Option<Source> {
+            if self.dest_range().contains(&dest) {
+                Some(dest - self.offset())
+            } else {
+                None
+            }
+        }
+
+        pub fn source_range(&self) -> Range<i64> {
+            self.source..self.source + self.range
+        }
+
+        pub fn dest_range(&self) -> Range<i64> {
+            self.dest..self.dest + self.range
+        }
+
+        // added to a source to get its dest
+        pub fn offset(&self) -> i64 {
+            self.dest - self.source
+        }
+    }
+
+    fn parse_range_line(input: &str) -> IResult<'_, Mapping> {
+        let (input, dest) = complete::i64(input)?;
+        let (input, _) = complete::space1(input)?;
+        let (input, source) = complete::i64(input)?;
+        let (input, _) = complete::space1(input)?;
+        let (input, range) = complete::i64(input)?;
+        Ok((
+            input,
+            Mapping {
+                dest,
+                source,
+                range,
+            },
+        ))
+    }
+
+    // `X-to-Y map:` followed by its mappings
+    fn parse_table(input: &str) -> IResult<'_, Table> {
+        let (input, (source, dest)) = context(
+            "a header like `seed-to-soil map:`",
+            terminated(separated_pair(alpha1, tag("-to-"), alpha1), tag(" map:")),
+        )(input)?;
+        let (input, _) = complete::newline(input)?;
+        let (input, mappings) = separated_list1(complete::newline, parse_range_line)(input)?;
+        Ok((
+            input,
+            Table {
+                source: source.into(),
+                dest: dest.into(),
+                mappings: mappings.into(),
+            },
+        ))
+    }
+
+    type Seed = i64;
+
+    // Sources not covered by any mapping map to themselves.
+    #[derive(Debug, Clone, Default)]
+    pub struct Mappings {
+        // sorted by source, the source ranges don't overlap
+        pub inner: Vec<Mapping>,
+    }
+
+    impl Mappings {
+        pub fn new(mut inner: Vec<Mapping>) -> Self {
+            inner.retain(|mapping| mapping.range > 0);
+            inner.sort_by_key(|mapping| mapping.source);
+            Self { inner }
+        }
+
+        // Splits `range` at the mapping boundaries, with the offset of the mapping
+        // covering each piece, `None` for the pieces no mapping covers.
+        fn pieces(&self, range: Range<Source>) -> Vec<(Range<Source>, Option<i64>)> {
+            let mut pieces = vec![];
+            let mut start = range.start;
+
+            for mapping in &self.inner {
+                let source = mapping.source_range();
+                if source.end <= start {
+                    continue;
+                }
+                if source.start >= range.end {
+                    break;
+                }
+                if start < source.start {
+                    pieces.push((start..source.start, None));
+                    start = source.start;
+                }
+                let end = source.end.min(range.end);
+                pieces.push((start..end, Some(mapping.offset())));
+                start = end;
+            }
+
+            if start < range.end {
+                pieces.push((start..range.end, None));
+            }
+            pieces
+        }
+
+        // the dests of every source in `range`, as ranges
+        pub fn map_range(&self, range: Range<Source>) -> Vec<Range<Dest>> {
+            self.pieces(range)
+                .into_iter()
+                .map(|(piece, offset)| {
+                    let offset = offset.unwrap_or(0);
+                    piece.start + offset..piece.end + offset
+                })
+                .collect()
+        }
+
+        // a single table mapping like `self` followed by `next`
+        pub fn compose(&self, next: &Mappings) -> Mappings {
+            let mut inner = vec![];
+
+            for mapping in &self.inner {
+                for (dest, offset) in next.pieces(mapping.dest_range()) {
+                    inner.push(Mapping {
+                        dest: dest.start + offset.unwrap_or(0),
+                        source: dest.start - mapping.offset(),
+                        range: dest.end - dest.start,
+                    });
+                }
+            }
+
+            // sources `self` leaves as they are go straight to `next`
+            for mapping in &next.inner {
+                for (source, offset) in self.pieces(mapping.source_range()) {
+                    if offset.is_none() {
+                        inner.push(Mapping {
+                            dest: source.start + mapping.offset(),
+                            source: source.start,
+                            range: source.end - source.start,
+                        });
+                    }
+                }
+            }
+
+            Mappings::new(inner)
+        }
+
+        // `None` unless every mapped dest comes from exactly one mapped source,
+        // as in the puzzle, where each table only shuffles ranges around
+        pub fn inverse(&self) -> Option<Mappings> {
+            let sources = union(self.inner.iter().map(Mapping::source_range));
+            let dests = union(self.inner.iter().map(Mapping::dest_range));
+
+            (sources == dests).then(|| {
+                Mappings::new(
+                    self.inner
+                        .iter()
+                        .map(|mapping| Mapping {
+                            dest: mapping.source,
+                            source: mapping.dest,
+                            range: mapping.range,
+                        })
+                        .collect(),
+                )
+            })
+        }
+
+        pub fn source_to_dest(&self, source: Source) -> Dest {
+            let dest = self
+                .inner
+                .iter()
+                .map(|mapping| mapping.source_to_dest(source))
+                .find(Option::is_some);
+
+            if dest.is_none() {
+                source
+            } else {
+                dest.unwrap().unwrap()
+            }
+        }
+
+        // every source mapped to `dest`, none or several when the table isn't a permutation
+        pub fn dest_to_source(&self, dest: Dest) -> Vec<Source> {
+            let mut sources = self
+                .inner
+                .iter()
+                .filter_map(|mapping| mapping.dest_to_source(dest))
+                .collect::<Vec<_>>();
+
+            if !self
+                .inner
+                .iter()
+                .any(|mapping| mapping.source_range().contains(&dest))
+            {
+                sources.push(dest);
+            }
+            sources
+        }
+    }
+
+    impl From<Vec<Mapping>> for Mappings {
+        fn from(value: Vec<Mapping>) -> Self {
+            Self::new(value)
+        }
+    }
+
+    // merges overlapping and adjacent ranges
+    fn union(ranges: impl Iterator<Item = Range<i64>>) -> Vec<Range<i64>> {
+        let mut ranges = ranges.collect::<Vec<_>>();
+        ranges.sort_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<i64>> = vec![];
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+
+    #[derive(Debug)]
+    pub struct Table {
+        pub source: String,
+        pub dest: String,
+        pub mappings: Mappings,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum QueryError {
+        UnknownCategory(String),
+        // or only through maps that can't be inverted
+        NotConnected { from: String, to: String },
+    }
+
+    impl std::fmt::Display for QueryError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                QueryError::UnknownCategory(category) => write!(f, "no `{category}` map"),
+                QueryError::NotConnected { from, to } => {
+                    write!(f, "no chain of maps from `{from}` to `{to}`")
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for QueryError {}
+
+    // the input parsed, but doesn't connect the categories a part asks about
+    impl From<QueryError> for utils::SolveError {
+        fn from(err: QueryError) -> Self {
+            utils::SolveError::no_answer(err.to_string())
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Parsed {
+        pub seeds: Vec<Seed>,
+        // in the order of the input
+        pub tables: Vec<Table>,
+    }
+
+    impl Parsed {
+        // A single table from `from` to `to` through the maps between them.
+        pub fn query(&self, from: &str, to: &str) -> Result<Mappings, QueryError> {
+            Ok(self
+                .chain(from, to)?
+                .iter()
+                .fold(Mappings::default(), |composed, table| {
+                    composed.compose(table)
+                }))
+        }
+
+        // The maps from `from` to `to` in order,
+        // maps are followed backwards when their inverse exists.
+        pub fn chain(&self, from: &str, to: &str) -> Result<Vec<Mappings>, QueryError> {
+            for category in [from, to] {
+                if category != "seed"
+                    && !self
+                        .tables
+                        .iter()
+                        .any(|table| table.source == category || table.dest == category)
+                {
+                    return Err(QueryError::UnknownCategory(category.into()));
+                }
+            }
+
+            let inverses = self
+                .tables
+                .iter()
+                .map(|table| table.mappings.inverse())
+                .collect::<Vec<_>>();
+
+            // breadth first, with the category and table every category was reached through
+            let mut reached = HashMap::<&str, Option<(&str, &Mappings)>>::from([(from, None)]);
+            let mut queue = VecDeque::from([from]);
+
+            while let Some(category) = queue.pop_front() {
+                for (table, inverse) in self.tables.iter().zip(&inverses) {
+                    let edges = [
+                        (&table.source, &table.dest, Some(&table.mappings)),
+                        (&table.dest, &table.source, inverse.as_ref()),
+                    ];
+                    for (source, dest, mappings) in edges {
+                        let Some(mappings) = mappings else {
+                            continue;
+                        };
+                        if source == category && !reached.contains_key(dest.as_str()) {
+                            reached.insert(dest, Some((category, mappings)));
+                            queue.push_back(dest);
+                        }
+                    }
+                }
+            }
+
+            let mut chain = vec![];
+            let mut category = to;
+            while let Some((previous, mappings)) =
+                reached
+                    .get(category)
+                    .ok_or_else(|| QueryError::NotConnected {
+                        from: from.into(),
+                        to: to.into(),
+                    })?
+            {
+                chain.push((*mappings).clone());
+                category = previous;
+            }
+
+            chain.reverse();
+            Ok(chain)
+        }
+
+        // The other way around from mapping `sources` forwards: tries every `to` from 0 upward,
+        // maps it back through the chain and stops at the first that came from `sources`.
+        pub fn reverse_search(
+            &self,
+            from: &str,
+            to: &str,
+            sources: &[Range<i64>],
+        ) -> Result<Option<i64>, QueryError> {
+            let chain = self.chain(from, to)?;
+
+            // past every range all the maps leave values as they are,
+            // so there is nothing left to find if the sources end before
+            let end = chain
+                .iter()
+                .flat_map(|table| &table.inner)
+                .flat_map(|mapping| [mapping.source_range().end, mapping.dest_range().end])
+                .chain(sources.iter().map(|range| range.end))
+                .max()
+                .unwrap_or(0);
+
+            Ok((0..end).find(|&candidate| {
+                chain
+                    .iter()
+                    .rev()
+                    .fold(vec![candidate], |dests, table| {
+                        dests
+                            .into_iter()
+                            .flat_map(|dest| table.dest_to_source(dest))
+                            .collect()
+                    })
+                    .iter()
+                    .any(|source| sources.iter().any(|range| range.contains(source)))
+            }))
+        }
+    }
+
+    pub fn parse_input(input: &str) -> Result<Parsed, utils::ParseError> {
+        let (seeds, tables) = parse_all(input, parse_almanac)?;
+
+        // part two reads the seeds as pairs of a start and a length
+        if let Some((last, _)) = seeds.chunks_exact(2).remainder().first() {
+            return Err(utils::ParseError::at(
+                input,
+                last,
+                "a length after the start",
+            ));
+        }
+        for pair in seeds.chunks_exact(2) {
+            let [(_, start), (length_at, length)] = [pair[0], pair[1]];
+            if length <= 0 {
+                return Err(utils::ParseError::at(input, length_at, "a positive length"));
+            }
+            if start.checked_add(length).is_none() {
+                return Err(utils::ParseError::at(
+                    input,
+                    length_at,
+                    "a length that keeps the range within 64 bits",
+                ));
+            }
+        }
+        let seeds = seeds.into_iter().map(|(_, seed)| seed).collect();
+
+        // every map must be reachable from `seed`, going either way through the others
+        let mut connected = HashSet::from(["seed"]);
+        loop {
+            let before = connected.len();
+            for (_, table) in &tables {
+                if connected.contains(table.source.as_str())
+                    || connected.contains(table.dest.as_str())
+                {
+                    connected.extend([table.source.as_str(), table.dest.as_str()]);
+                }
+            }
+            if connected.len() == before {
+                break;
+            }
+        }
+
+        if let Some((header, _)) = tables
+            .iter()
+            .find(|(_, table)| !connected.contains(table.source.as_str()))
+        {
+            return Err(utils::ParseError::at(
+                input,
+                header,
+                "a map connected to `seed`",
+            ));
+        }
+
+        Ok(Parsed {
+            seeds,
+            tables: tables.into_iter().map(|(_, table)| table).collect(),
+        })
+    }
+
+    // with the input starting at each seed and table, to point at them
+    type Almanac<'a> = (Vec<(&'a str, Seed)>, Vec<(&'a str, Table)>);
+
+    fn parse_almanac(input: &str) -> IResult<'_, Almanac<'_>> {
+        let (input, _) = context("`seeds: `", tag("seeds: "))(input)?;
+        let (input, seeds) = separated_list1(complete::space1, |input| {
+            complete::i64(input).map(|(rest, seed)| (rest, (input, seed)))
+        })(input)?;
+        let (input, _) = complete::newline(input)?;
+        let (input, _) = complete::newline(input)?;
+
+        let (input, tables) =
+            separated_list1(pair(complete::newline, complete::newline), |input| {
+                parse_table(input).map(|(rest, table)| (rest, (input, table)))
+            })(input)?;
+
+        Ok((input, (seeds, tables)))
+    }
+}
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+#[cfg(test)]
+fn test_input() -> &'static str {
+    "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<i64, utils::SolveError> {
+        let parsed = parse::parse_input(input)?;
+
+        let ranges = seed_ranges(&parsed.seeds);
+
+        let table = parsed.query("seed", "location")?;
+
+        ranges
+            .into_iter()
+            .flat_map(|range| table.map_range(range))
+            .map(|range| range.start)
+            .min()
+            .ok_or_else(|| utils::SolveError::no_answer("no seed range maps to a location"))
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(4917124));
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two_test_input() {
+        assert_eq!(part_two(test_input()), Ok(46));
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_composed() {
+        let parsed = parse::parse_input(test_input()).unwrap();
+        let table = parsed.query("seed", "location").unwrap();
+
+        for seed in 0..120 {
+            let location = parsed
+                .tables
+                .iter()
+                .fold(seed, |dest, table| table.mappings.source_to_dest(dest));
+            assert_eq!(table.source_to_dest(seed), location, "seed {seed}");
+            assert_eq!(
+                table.map_range(seed..seed + 1),
+                vec![location..location + 1]
+            );
+        }
+
+        // 79..93 goes through several mappings on its way
+        let mut locations = table.map_range(79..93);
+        locations.sort_by_key(|range| range.start);
+        assert_eq!(
+            locations
+                .iter()
+                .map(|range| range.end - range.start)
+                .sum::<i64>(),
+            14
+        );
+        assert_eq!(locations[0].start, 46);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse::*;
+    use super::*;
+
+    #[test]
+    fn test_query() {
+        let parsed = parse_input(test_input()).unwrap();
+
+        // seed 79 has soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78
+        let soil_to_light = parsed.query("soil", "light").unwrap();
+        assert_eq!(soil_to_light.source_to_dest(81), 74);
+
+        let location_to_seed = parsed.query("location", "seed").unwrap();
+        for (seed, location) in [(79, 82), (14, 43), (55, 86), (13, 35)] {
+            assert_eq!(location_to_seed.source_to_dest(location), seed);
+        }
+
+        assert_eq!(
+            parsed.query("seed", "fuel").unwrap_err(),
+            QueryError::UnknownCategory("fuel".into())
+        );
+    }
+
+    #[test]
+    fn test_dest_to_source() {
+        let parsed = parse_input(test_input()).unwrap();
+        let seed_to_soil = &parsed.tables[0].mappings;
+        assert_eq!(seed_to_soil.inner[0].dest_to_source(52), Some(50));
+        assert_eq!(seed_to_soil.inner[0].dest_to_source(50), None);
+        assert_eq!(seed_to_soil.dest_to_source(51), [99]);
+        assert_eq!(seed_to_soil.dest_to_source(81), [79]);
+        assert_eq!(seed_to_soil.dest_to_source(10), [10]);
+
+        // 0 and 1 both go to 1, nothing goes to 0
+        let mappings = Mappings::new(vec![Mapping {
+            dest: 1,
+            source: 0,
+            range: 1,
+        }]);
+        assert_eq!(mappings.dest_to_source(1), [0, 1]);
+        assert_eq!(mappings.dest_to_source(0), []);
+    }
+
+    #[test]
+    fn test_reverse_search() {
+        let parsed = parse_input(test_input()).unwrap();
+
+        let seeds = parsed
+            .seeds
+            .iter()
+            .map(|seed| *seed..seed + 1)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parsed.reverse_search("seed", "location", &seeds),
+            Ok(Some(35))
+        );
+        assert_eq!(part_one::part_one(test_input()), Ok(35));
+
+        let seeds = seed_ranges(&parsed.seeds);
+        assert_eq!(
+            parsed.reverse_search("seed", "location", &seeds),
+            Ok(Some(46))
+        );
+        assert_eq!(part_two::part_two(test_input()), Ok(46));
+
+        assert_eq!(parsed.reverse_search("seed", "location", &[]), Ok(None));
+    }
+
+    #[test]
+    fn test_variant_almanac() {
+        // out of order, with a map that can't be inverted
+        let parsed = parse_input(
+            "seeds: 1 5
+
+soil-to-water map:
+10 0 2
+
+seed-to-soil map:
+0 1 1
+1 0 1",
+        )
+        .unwrap();
+
+        let seed_to_water = parsed.query("seed", "water").unwrap();
+        assert_eq!(
+            [0, 1, 2].map(|seed| seed_to_water.source_to_dest(seed)),
+            [11, 10, 2]
+        );
+        assert_eq!(
+            parsed.query("water", "seed").unwrap_err(),
+            QueryError::NotConnected {
+                from: "water".into(),
+                to: "seed".into()
+            }
+        );
+        assert_eq!(parsed.query("soil", "seed").unwrap().source_to_dest(0), 1);
+    }
+
+    #[test]
+    fn test_not_connected() {
+        assert_eq!(
+            parse_input(
+                "seeds: 1 5
+
+seed-to-soil map:
+0 1 1
+
+water-to-light map:
+0 1 1"
+            )
+            .unwrap_err(),
+            utils::ParseError::new(6, 1, "a map connected to `seed`")
+        );
+        assert_eq!(
+            part_one::part_one("seeds: 1 5\n\nseed-to-soil map:\n0 1 1"),
+            Err(QueryError::UnknownCategory("location".into()).into())
+        );
+    }
+
+    #[test]
+    fn test_invalid_seeds() {
+        let error = |seeds: &str| {
+            parse_input(&format!(
+                "seeds: {seeds}
+
+seed-to-soil map:
+0 1 1"
+            ))
+            .unwrap_err()
+        };
+        assert_eq!(
+            error("5"),
+            utils::ParseError::new(1, 8, "a length after the start")
+        );
+        assert_eq!(
+            error("1 5 7"),
+            utils::ParseError::new(1, 12, "a length after the start")
+        );
+        assert_eq!(
+            error("5 0"),
+            utils::ParseError::new(1, 10, "a positive length")
+        );
+        assert_eq!(
+            error("9223372036854775807 5"),
+            utils::ParseError::new(1, 28, "a length that keeps the range within 64 bits")
+        );
+    }
+}
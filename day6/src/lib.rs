@@ -0,0 +1,288 @@
+mod part_one {
+    use super::Race;
+    use utils::ParseError;
+
+    pub fn part_one(races: Vec<Race>) -> Result<usize, ParseError> {
+        races
+            .into_iter()
+            .map(|race| race.ways_to_beat_the_record())
+            .try_fold(1usize, |acc, x| acc.checked_mul(x))
+            .ok_or_else(|| {
+                ParseError::new(1, 1, "times whose ways to win multiply into less than 2^64")
+            })
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        let races = crate::parse::parse_input(&crate::input()).unwrap();
+        assert_eq!(part_one(races), Ok(219849));
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one_overflow() {
+        let races = crate::parse::parse_input(
+            "Time:      4000000000 4000000000 4000000000 4000000000\nDistance:  0 0 0 0",
+        )
+        .unwrap();
+        assert_eq!(
+            part_one(races),
+            Err(ParseError::new(
+                1,
+                1,
+                "times whose ways to win multiply into less than 2^64"
+            ))
+        );
+    }
+}
+
+mod part_two {
+    use super::Race;
+    use utils::ParseError;
+
+    // `12` and `345` join into `12345`, `None` when that overflows
+    fn join(numbers: impl Iterator<Item = u128>) -> Option<u128> {
+        numbers.into_iter().try_fold(0u128, |joined, n| {
+            let digits = n.checked_ilog10().unwrap_or(0) + 1;
+            joined
+                .checked_mul(10u128.checked_pow(digits)?)?
+                .checked_add(n)
+        })
+    }
+
+    pub fn part_two(races: Vec<Race>) -> Result<usize, ParseError> {
+        let time = join(races.iter().map(|race| race.time as u128))
+            .and_then(|time| u64::try_from(time).ok())
+            .ok_or_else(|| ParseError::new(1, 1, "times that join into less than 2^64"))?;
+
+        let distance = join(races.iter().map(|race| race.distance))
+            .ok_or_else(|| ParseError::new(2, 1, "distances that join into less than 2^128"))?;
+
+        Ok(Race { time, distance }.ways_to_beat_the_record())
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        let races = crate::parse::parse_input(&crate::input()).unwrap();
+        assert_eq!(part_two(races), Ok(29432455));
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two_large() {
+        // the distance only fits in 128 bits
+        let races = crate::parse::parse_input(
+            "Time:      10000      100000\nDistance:  2000000000 1000000000",
+        )
+        .unwrap();
+        assert_eq!(part_two(races), Ok(4472359557));
+
+        let races =
+            crate::parse::parse_input("Time:      9999999999 9999999999\nDistance:  1 1").unwrap();
+        assert_eq!(
+            part_two(races),
+            Err(ParseError::new(1, 1, "times that join into less than 2^64"))
+        );
+
+        let races =
+            crate::parse::parse_input(&format!("Time:      1 2\nDistance:  {} 1", u128::MAX))
+                .unwrap();
+        assert_eq!(
+            part_two(races),
+            Err(ParseError::new(
+                2,
+                1,
+                "distances that join into less than 2^128"
+            ))
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Race {
+    pub time: u64,
+    // up to `time^2 / 4` to be beaten at all
+    pub distance: u128,
+}
+
+impl Race {
+    pub fn ways_to_beat_the_record(&self) -> usize {
+        let holds = self.winning_holds();
+        (holds.end - holds.start) as usize
+    }
+
+    // Holding `h` goes `h * (time - h)`, which beats the record strictly between
+    // the roots of `h^2 - time * h + distance`. The distance is symmetric around `time / 2`,
+    // so the holds end as far from `time` as they start from 0. Empty when no hold wins.
+    pub fn winning_holds(&self) -> std::ops::Range<u64> {
+        // the best hold, half of the time, doesn't win either
+        if !self.beats_record_with_hold(self.time / 2) {
+            return 0..0;
+        }
+
+        let (time, distance) = (self.time as u128, self.distance);
+        let discriminant = time * time - 4 * distance;
+
+        // the lower root rounded down, off by one at most for the integer square root
+        let mut first = ((time - discriminant.isqrt()) / 2) as u64;
+        while !self.beats_record_with_hold(first) {
+            first += 1;
+        }
+        while first > 0 && self.beats_record_with_hold(first - 1) {
+            first -= 1;
+        }
+
+        first..self.time - first + 1
+    }
+
+    pub fn beats_record_with_hold(&self, hold_millis: u64) -> bool {
+        let Some(time_remaining) = self.time.checked_sub(hold_millis) else {
+            return false;
+        };
+        let speed = hold_millis;
+        speed as u128 * time_remaining as u128 > self.distance
+    }
+}
+
+mod parse {
+    use super::Race;
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{self, space1},
+        error::context,
+        multi::separated_list1,
+        sequence::preceded,
+    };
+    use utils::parse::{parse_all, IResult};
+    use utils::ParseError;
+
+    fn parse_numbers<'a, T>(
+        number: impl FnMut(&'a str) -> IResult<'a, T>,
+    ) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+        preceded(space1, separated_list1(complete::space1, number))
+    }
+
+    pub fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+        let (times, distances) = parse_all(input, |input| {
+            let (input, _) = context("`Time:`", tag("Time:"))(input)?;
+            let (input, times) = parse_numbers(complete::u64)(input)?;
+            let (input, _) = complete::newline(input)?;
+
+            let (input, _) = context("`Distance:`", tag("Distance:"))(input)?;
+            let (input, distances) = parse_numbers(complete::u128)(input)?;
+
+            Ok((input, (times, distances)))
+        })?;
+
+        if times.len() != distances.len() {
+            let line = input.lines().nth(1).unwrap().trim_end();
+            return Err(ParseError::new(
+                2,
+                line.len() + 1,
+                format!("{} distances, one for every time", times.len()),
+            ));
+        }
+
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Race { time, distance })
+            .collect())
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_parse_input_errors() {
+        assert_eq!(
+            parse_input("Time: 7 15\nDistance: 9"),
+            Err(ParseError::new(2, 12, "2 distances, one for every time"))
+        );
+        assert_eq!(
+            parse_input("Time: 7\nDist: 9"),
+            Err(ParseError::new(2, 1, "`Distance:`"))
+        );
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+pub struct Day6;
+
+impl utils::Solution for Day6 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(parse::parse_input(input)?)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(parse::parse_input(input)?)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn race(time: u64, distance: u128) -> Race {
+        Race { time, distance }
+    }
+
+    #[test]
+    fn test_winning_holds() {
+        assert_eq!(race(7, 9).winning_holds(), 2..6);
+        assert_eq!(race(15, 40).winning_holds(), 4..12);
+        // 10 and 20 only tie the record
+        assert_eq!(race(30, 200).winning_holds(), 11..20);
+        assert_eq!(race(71530, 940200).ways_to_beat_the_record(), 71503);
+    }
+
+    #[test]
+    fn test_no_winning_holds() {
+        // holding 2 ties the record
+        assert!(race(4, 4).winning_holds().is_empty());
+        assert!(race(5, 10).winning_holds().is_empty());
+        assert!(race(0, 0).winning_holds().is_empty());
+        assert_eq!(race(2, 0).ways_to_beat_the_record(), 1);
+    }
+
+    #[test]
+    fn test_large_races() {
+        let race = race(u64::MAX, u64::MAX as u128);
+        let holds = race.winning_holds();
+        assert_eq!(holds, 2..u64::MAX - 1);
+        assert!(race.beats_record_with_hold(holds.start));
+        assert!(!race.beats_record_with_hold(holds.start - 1));
+
+        // holding half of the time goes 1 << 62 exactly
+        assert!(race_at_peak(1 << 62).winning_holds().is_empty());
+        assert_eq!(
+            race_at_peak((1 << 62) - 1).winning_holds(),
+            1 << 31..(1 << 31) + 1
+        );
+    }
+
+    fn race_at_peak(distance: u128) -> Race {
+        race(1 << 32, distance)
+    }
+
+    #[test]
+    fn test_winning_holds_against_every_hold() {
+        for time in 0..60 {
+            for distance in 0..(time * time / 4 + 2) as u128 {
+                let race = race(time, distance);
+                let wins = (0..=time).filter(|hold| race.beats_record_with_hold(*hold));
+                assert_eq!(
+                    race.winning_holds().collect::<Vec<_>>(),
+                    wins.collect::<Vec<_>>(),
+                    "{race:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,566 @@
+mod part_one {
+    use super::Rules;
+
+    pub fn part_one(input: &str) -> Result<usize, utils::ParseError> {
+        Rules::normal().calc(input)
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&super::input()), Ok(254024898));
+    }
+}
+
+mod part_two {
+    use super::Rules;
+
+    pub fn part_two(input: &str) -> Result<usize, utils::ParseError> {
+        Rules::abnormal_joker().calc(input)
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&super::input()), Ok(254115617));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandKind {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl HandKind {
+    // `counts` are how many cards of each label a hand has, most frequent first
+    pub fn from_counts(counts: &[usize]) -> Self {
+        use HandKind::*;
+        let first = counts.first().copied().unwrap_or(0);
+        let second = counts.get(1).copied().unwrap_or(0);
+
+        match (first, second) {
+            (5.., _) => FiveOfAKind,
+            (4, _) => FourOfAKind,
+            (3, 2..) => FullHouse,
+            (3, _) => ThreeOfAKind,
+            (2, 2..) => TwoPair,
+            (2, _) => OnePair,
+            _ => HighCard,
+        }
+    }
+
+    pub fn weight(&self) -> u32 {
+        use HandKind::*;
+        match self {
+            FiveOfAKind => 6,
+            FourOfAKind => 5,
+            FullHouse => 4,
+            ThreeOfAKind => 3,
+            TwoPair => 2,
+            OnePair => 1,
+            HighCard => 0,
+        }
+    }
+}
+
+impl Ord for HandKind {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.weight().cmp(&other.weight())
+    }
+}
+
+impl PartialOrd for HandKind {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hand(String);
+
+impl AsRef<str> for Hand {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Hand {
+    pub fn new(value: &str) -> Self {
+        Self(value.into())
+    }
+}
+
+pub type Bid = u32;
+
+// Orders hands the way a game does: weaker kinds first, then label by label.
+// Identical hands are equal.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RankedHand {
+    pub kind: HandKind,
+    // of every card in the hand, 0 for the weakest label
+    pub strengths: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandReport {
+    // 1 for the weakest hand
+    pub rank: usize,
+    pub hand: Hand,
+    pub kind: HandKind,
+    // `None` for hands without wildcards
+    pub substitution: Option<Hand>,
+    pub bid: Bid,
+    // bid × rank
+    pub winnings: usize,
+}
+
+// Every hand from the weakest to the strongest,
+// `Display` prints it as a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report(pub Vec<HandReport>);
+
+impl Report {
+    pub fn total(&self) -> usize {
+        self.0.iter().map(|hand| hand.winnings).sum()
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("rank,hand,kind,substitution,bid,winnings\n");
+        for hand in &self.0 {
+            csv += &format!(
+                "{},{},{:?},{},{},{}\n",
+                hand.rank,
+                hand.hand.as_ref(),
+                hand.kind,
+                hand.substitution.as_ref().map_or("", Hand::as_ref),
+                hand.bid,
+                hand.winnings
+            );
+        }
+        csv
+    }
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .0
+            .iter()
+            .map(|hand| hand.hand.as_ref().chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("hand".len());
+
+        writeln!(
+            f,
+            "{:>5}  {:<width$}  {:<12}  {:<width$}  {:>6}  {:>10}",
+            "rank", "hand", "kind", "with", "bid", "winnings"
+        )?;
+        for hand in &self.0 {
+            writeln!(
+                f,
+                "{:>5}  {:<width$}  {:<12}  {:<width$}  {:>6}  {:>10}",
+                hand.rank,
+                hand.hand.as_ref(),
+                format!("{:?}", hand.kind),
+                hand.substitution.as_ref().map_or("", Hand::as_ref),
+                hand.bid,
+                hand.winnings
+            )?;
+        }
+        write!(
+            f,
+            "{:>5}  {:>w$}",
+            "total",
+            self.total(),
+            w = 2 * width + 36
+        )
+    }
+}
+
+// A variant of the game, described by its labels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    // strongest first
+    pub labels: Vec<char>,
+    // stand in for whichever label makes the hand strongest
+    pub wildcards: Vec<char>,
+    pub hand_size: usize,
+}
+
+impl Rules {
+    pub fn new(labels: &str, wildcards: &str, hand_size: usize) -> Self {
+        Self {
+            labels: labels.chars().collect(),
+            wildcards: wildcards.chars().collect(),
+            hand_size,
+        }
+    }
+
+    pub fn normal() -> Self {
+        Self::new("AKQJT98765432", "", 5)
+    }
+
+    // `J` is the weakest label and a wildcard
+    pub fn abnormal_joker() -> Self {
+        Self::new("AKQT98765432J", "J", 5)
+    }
+
+    pub fn kind(&self, hand: &Hand) -> HandKind {
+        let mut counts = std::collections::HashMap::<char, usize>::new();
+        for c in self.substitute(hand).as_ref().chars() {
+            *counts.entry(c).or_default() += 1;
+        }
+
+        let mut counts = counts.into_values().collect::<Vec<_>>();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+
+        HandKind::from_counts(&counts)
+    }
+
+    // The hand with its wildcards replaced by the label making the strongest kind:
+    // the most frequent other label, the strongest of them on a tie,
+    // or the strongest label of all when the hand is only wildcards.
+    pub fn substitute(&self, hand: &Hand) -> Hand {
+        let mut counts = std::collections::HashMap::<char, usize>::new();
+        for c in hand.as_ref().chars() {
+            if !self.wildcards.contains(&c) {
+                *counts.entry(c).or_default() += 1;
+            }
+        }
+
+        let best = counts
+            .into_iter()
+            .max_by_key(|(label, count)| (*count, self.strength(*label)))
+            .map(|(label, _)| label)
+            .or_else(|| {
+                self.labels
+                    .iter()
+                    .copied()
+                    .find(|label| !self.wildcards.contains(label))
+            });
+
+        let Some(best) = best else {
+            return hand.clone();
+        };
+
+        Hand(
+            hand.as_ref()
+                .chars()
+                .map(|c| if self.wildcards.contains(&c) { best } else { c })
+                .collect(),
+        )
+    }
+
+    pub fn rank(&self, hand: &Hand) -> RankedHand {
+        RankedHand {
+            kind: self.kind(hand),
+            strengths: hand.as_ref().chars().map(|c| self.strength(c)).collect(),
+        }
+    }
+
+    fn strength(&self, label: char) -> usize {
+        self.labels.len() - 1 - self.labels.iter().position(|l| *l == label).unwrap()
+    }
+
+    pub fn calc(&self, input: &str) -> Result<usize, utils::ParseError> {
+        Ok(self.report(input)?.total())
+    }
+
+    pub fn report(&self, input: &str) -> Result<Report, utils::ParseError> {
+        let mut hands = utils::parse::parse_lines(input, |line| self.parse_line(line))?;
+
+        // stable, identical hands keep their order in the input
+        hands.sort_by_cached_key(|(hand, _)| self.rank(hand));
+
+        Ok(Report(
+            hands
+                .into_iter()
+                .enumerate()
+                // from weakest to strongest hand order
+                .map(|(i, (hand, bid))| HandReport {
+                    rank: i + 1,
+                    kind: self.kind(&hand),
+                    substitution: hand
+                        .as_ref()
+                        .chars()
+                        .any(|c| self.wildcards.contains(&c))
+                        .then(|| self.substitute(&hand)),
+                    winnings: (i + 1) * bid as usize,
+                    hand,
+                    bid,
+                })
+                .collect(),
+        ))
+    }
+
+    pub fn parse_line(&self, value: &str) -> Result<(Hand, Bid), utils::ParseError> {
+        use utils::ParseError;
+
+        let expected_label = format!(
+            "a card label (one of `{}`)",
+            self.labels.iter().collect::<String>()
+        );
+
+        let mut chars = value.char_indices();
+        for i in 0..self.hand_size {
+            match chars.next() {
+                Some((_, c)) if self.labels.contains(&c) => {}
+                _ => return Err(ParseError::new(1, i + 1, expected_label)),
+            }
+        }
+        let (hand, rest) = value.split_at(chars.offset());
+
+        let bid = rest
+            .strip_prefix(' ')
+            .ok_or_else(|| ParseError::new(1, self.hand_size + 1, "` `"))?;
+
+        let bid = bid
+            .parse()
+            .map_err(|_| ParseError::new(1, self.hand_size + 2, "a bid"))?;
+
+        Ok((Hand::new(hand), bid))
+    }
+}
+
+pub struct Day7;
+
+impl utils::Solution for Day7 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+use std::cmp::Ordering;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use utils::ParseError;
+
+    #[test]
+    fn test_parse_line_errors() {
+        assert_eq!(
+            Rules::normal().parse_line("32T3X 765"),
+            Err(ParseError::new(
+                1,
+                5,
+                "a card label (one of `AKQJT98765432`)"
+            ))
+        );
+        assert_eq!(
+            Rules::normal().parse_line("32T"),
+            Err(ParseError::new(
+                1,
+                4,
+                "a card label (one of `AKQJT98765432`)"
+            ))
+        );
+        assert_eq!(
+            Rules::normal().parse_line("32T3KK 765"),
+            Err(ParseError::new(1, 6, "` `"))
+        );
+        assert_eq!(
+            Rules::normal().parse_line("32T3K -5"),
+            Err(ParseError::new(1, 7, "a bid"))
+        );
+    }
+
+    fn kinds(rules: &Rules, hands: &[&str]) -> Vec<HandKind> {
+        hands
+            .iter()
+            .map(|hand| rules.kind(&Hand::new(hand)))
+            .collect()
+    }
+
+    #[test]
+    fn test_kind() {
+        use HandKind::*;
+        let hands = [
+            "32T3K", "T55J5", "KK677", "KTJJT", "QQQJA", "JJJJJ", "2345J",
+        ];
+
+        assert_eq!(
+            kinds(&Rules::normal(), &hands),
+            [
+                OnePair,
+                ThreeOfAKind,
+                TwoPair,
+                TwoPair,
+                ThreeOfAKind,
+                FiveOfAKind,
+                HighCard
+            ]
+        );
+        assert_eq!(
+            kinds(&Rules::abnormal_joker(), &hands),
+            [
+                OnePair,
+                FourOfAKind,
+                TwoPair,
+                FourOfAKind,
+                FourOfAKind,
+                FiveOfAKind,
+                OnePair
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variants() {
+        use HandKind::*;
+
+        let two_jokers = Rules::new("AKQT98765432JW", "JW", 5);
+        assert_eq!(
+            kinds(&two_jokers, &["23JW4", "2JW33", "AAJWK", "JWJWA"]),
+            [ThreeOfAKind, FourOfAKind, FourOfAKind, FiveOfAKind]
+        );
+
+        let six_cards = Rules::new("AKQT98765432J", "J", 6);
+        assert_eq!(
+            kinds(&six_cards, &["AAAKKK", "23456J", "2233JJ", "AAAAAA"]),
+            [FullHouse, OnePair, FourOfAKind, FiveOfAKind]
+        );
+        assert_eq!(
+            six_cards.parse_line("AAAKKK 12"),
+            Ok((Hand::new("AAAKKK"), 12))
+        );
+        assert_eq!(
+            six_cards.parse_line("AAAKK 12"),
+            Err(ParseError::new(
+                1,
+                6,
+                "a card label (one of `AKQT98765432J`)"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_calc() {
+        let input = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+        assert_eq!(Rules::normal().calc(input), Ok(6440));
+        assert_eq!(Rules::abnormal_joker().calc(input), Ok(5905));
+    }
+
+    #[test]
+    fn test_report() {
+        let input = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+        let report = Rules::abnormal_joker().report(input).unwrap();
+
+        assert_eq!(report.total(), 5905);
+        assert_eq!(
+            report.0[4],
+            HandReport {
+                rank: 5,
+                hand: Hand::new("KTJJT"),
+                kind: HandKind::FourOfAKind,
+                substitution: Some(Hand::new("KTTTT")),
+                bid: 220,
+                winnings: 1100,
+            }
+        );
+        assert_eq!(
+            report.to_csv(),
+            "rank,hand,kind,substitution,bid,winnings
+1,32T3K,OnePair,,765,765
+2,KK677,TwoPair,,28,56
+3,T55J5,FourOfAKind,T5555,684,2052
+4,QQQJA,FourOfAKind,QQQQA,483,1932
+5,KTJJT,FourOfAKind,KTTTT,220,1100
+"
+        );
+        assert_eq!(
+            report.to_string(),
+            " rank  hand   kind          with      bid    winnings
+    1  32T3K  OnePair                 765         765
+    2  KK677  TwoPair                  28          56
+    3  T55J5  FourOfAKind   T5555     684        2052
+    4  QQQJA  FourOfAKind   QQQQA     483        1932
+    5  KTJJT  FourOfAKind   KTTTT     220        1100
+total                                            5905"
+        );
+    }
+
+    #[test]
+    fn test_substitute() {
+        let rules = Rules::abnormal_joker();
+        // ties go to the strongest label
+        assert_eq!(rules.substitute(&Hand::new("2J3KA")), Hand::new("2A3KA"));
+        assert_eq!(rules.substitute(&Hand::new("JJJJJ")), Hand::new("AAAAA"));
+        assert_eq!(rules.substitute(&Hand::new("23456")), Hand::new("23456"));
+
+        for hand in ["2JJ33", "KTJJT", "JJJJ2", "A2J3J"] {
+            let hand = Hand::new(hand);
+            assert_eq!(
+                Rules::normal().kind(&rules.substitute(&hand)),
+                rules.kind(&hand)
+            );
+        }
+    }
+
+    #[test]
+    fn test_duplicate_hands() {
+        // identical hands keep their order in the input
+        let input = "32T3K 1\nKK677 3\n32T3K 2";
+        assert_eq!(Rules::normal().calc(input), Ok(1 + 2 * 2 + 3 * 3));
+    }
+
+    #[test]
+    fn test_total_above_u32() {
+        let input = "AAAAA 4294967295\nKKKKK 4294967295";
+        assert_eq!(Rules::normal().calc(input), Ok(3 * 4294967295));
+    }
+
+    fn hand() -> impl Strategy<Value = Hand> {
+        proptest::collection::vec(proptest::sample::select(Rules::normal().labels), 5)
+            .prop_map(|labels| Hand::new(&labels.into_iter().collect::<String>()))
+    }
+
+    proptest! {
+        #[test]
+        fn test_rank_is_a_total_order(a in hand(), b in hand(), c in hand()) {
+            for rules in [Rules::normal(), Rules::abnormal_joker()] {
+                let (a_rank, b_rank, c_rank) = (rules.rank(&a), rules.rank(&b), rules.rank(&c));
+
+                prop_assert_eq!(a_rank.cmp(&b_rank), b_rank.cmp(&a_rank).reverse());
+                prop_assert_eq!(a_rank == b_rank, a == b);
+                if a_rank <= b_rank && b_rank <= c_rank {
+                    prop_assert!(a_rank <= c_rank);
+                }
+                if a_rank.kind != b_rank.kind {
+                    prop_assert_eq!(a_rank.cmp(&b_rank), a_rank.kind.cmp(&b_rank.kind));
+                }
+            }
+        }
+
+        #[test]
+        fn test_sorted_hands_are_ordered(mut hands in proptest::collection::vec(hand(), 0..50)) {
+            let rules = Rules::abnormal_joker();
+            hands.sort_by_cached_key(|hand| rules.rank(hand));
+
+            for (i, lhs) in hands.iter().enumerate() {
+                for rhs in &hands[i..] {
+                    prop_assert!(rules.rank(lhs) <= rules.rank(rhs));
+                }
+            }
+        }
+    }
+}
@@ -1,222 +1,52 @@
-mod part_one {
-    use super::{NormalRules, Rules};
+use day7::Rules;
+use utils::cli::{fail, usage_error};
 
-    pub fn part_one() -> u32 {
-        NormalRules.calc()
-    }
-
-    #[cfg(test)]
-    #[test]
-    fn test_part_one() {
-        assert_eq!(part_one(), 254024898);
-    }
-}
-
-mod part_two {
-    use super::{AbnormalJoker, Rules};
-
-    pub fn part_two() -> u32 {
-        AbnormalJoker.calc()
-    }
-
-    #[cfg(test)]
-    #[test]
-    fn test_part_two() {
-        assert_eq!(part_two(), 254115617);
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
-pub enum HandKind {
-    FiveOfAKind,
-    FourOfAKind,
-    FullHouse,
-    ThreeOfAKind,
-    TwoPair,
-    OnePair,
-    HighCard,
-}
-
-impl HandKind {
-    pub fn weight(&self) -> u32 {
-        use HandKind::*;
-        match self {
-            FiveOfAKind => 6,
-            FourOfAKind => 5,
-            FullHouse => 4,
-            ThreeOfAKind => 3,
-            TwoPair => 2,
-            OnePair => 1,
-            HighCard => 0,
-        }
-    }
-}
-
-impl Ord for HandKind {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.weight().cmp(&other.weight())
-    }
-}
-
-#[derive(Debug)]
-pub struct Hand(String);
-
-impl AsRef<str> for Hand {
-    fn as_ref(&self) -> &str {
-        &self.0
-    }
-}
-
-impl Hand {
-    pub fn new(value: &str) -> Self {
-        Self(value.into())
-    }
-}
+const USAGE: &str = "usage: day7 [--report <table|csv>] [--part <1|2>] [input]
 
-pub type Bid = u32;
+Without `--report` prints both answers. Otherwise prints every hand
+with its rank and winnings under the rules of the given part, 1 by default.";
 
-trait Rules {
-    fn ordered_labels() -> &'static [char];
-    fn kind(hand: &Hand) -> HandKind;
-
-    fn calc(&self) -> u32 {
-        let mut hands = read_input()
-            .map(Result::unwrap)
-            .map(parse_line)
-            .collect::<Vec<_>>();
-
-        hands.sort_by(|(lhs, _), (rhs, _)| Self::kind(lhs).cmp(&Self::kind(rhs)));
-        hands.sort_by(|(lhs, _), (rhs, _)| Self::label_cmp(lhs, rhs));
-
-        hands
-            .into_iter()
-            .enumerate()
-            // from weakest to strongest hand order
-            .fold(0, |acc, (i, (_hand, bid))| acc + ((i + 1) * bid as usize)) as u32
-    }
-
-    fn label_cmp(lhs: &Hand, rhs: &Hand) -> Ordering {
-        if Self::kind(lhs) == Self::kind(rhs) {
-            lhs.as_ref()
-                .chars()
-                .zip(rhs.as_ref().chars())
-                .find_map(|(self_c, other_c)| {
-                    match label_to_weight(self_c, Self::ordered_labels())
-                        .cmp(&label_to_weight(other_c, Self::ordered_labels()))
-                    {
-                        Ordering::Equal => None,
-                        v => Some(v),
-                    }
-                })
-                .unwrap()
-        } else {
-            Ordering::Equal
-        }
-    }
-}
-
-fn label_to_weight(value: char, labels: &[char]) -> u32 {
-    (labels.len()
-        - labels
-            .clone()
-            .into_iter()
-            .position(|v| v == &value)
-            .unwrap()) as u32
-}
-
-struct NormalRules;
-struct AbnormalJoker;
-
-impl Rules for NormalRules {
-    fn ordered_labels() -> &'static [char] {
-        &[
-            'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
-        ]
-    }
-
-    fn kind(hand: &Hand) -> HandKind {
-        use std::collections::HashMap;
-        let char_count = hand.as_ref().chars().fold(HashMap::new(), |mut map, c| {
-            *map.entry(c).or_insert(0) += 1;
-            map
-        });
-
-        let mut counts = char_count.values().collect::<Vec<_>>();
-        counts.sort();
-        counts.reverse();
-
-        use HandKind::*;
+fn main() {
+    let Some(args) = utils::cli::flags_or_run::<day7::Day7>() else {
+        return;
+    };
 
-        match counts[..] {
-            [5] => FiveOfAKind,
-            [4, 1] => FourOfAKind,
-            [3, 2] => FullHouse,
-            [3, 1, 1] => ThreeOfAKind,
-            [2, 2, 1] => TwoPair,
-            [2, 1, 1, 1] => OnePair,
-            [1, 1, 1, 1, 1] => HighCard,
-            _ => unreachable!(),
+    let mut csv = None;
+    let mut rules = Rules::normal();
+    let mut path = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--report" | "--part" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error(USAGE, format!("missing value for `{arg}`")));
+                match (arg.as_str(), value.as_str()) {
+                    ("--report", "table") => csv = Some(false),
+                    ("--report", "csv") => csv = Some(true),
+                    ("--part", "1") => rules = Rules::normal(),
+                    ("--part", "2") => rules = Rules::abnormal_joker(),
+                    _ => usage_error(USAGE, format!("invalid value `{value}` for `{arg}`")),
+                }
+            }
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg.as_str()),
+            _ => usage_error(USAGE, format!("unexpected argument `{arg}`")),
         }
     }
-}
-
-impl Rules for AbnormalJoker {
-    fn ordered_labels() -> &'static [char] {
-        &[
-            'A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J',
-        ]
-    }
-
-    fn kind(hand: &Hand) -> HandKind {
-        let j_count = hand.as_ref().chars().filter(|c| *c == 'J').count();
-
-        if j_count == 0 {
-            return NormalRules::kind(hand);
-        }
-
-        use HandKind::*;
-        match (NormalRules::kind(hand), j_count) {
-            (FiveOfAKind, 5) => FiveOfAKind,
 
-            (FourOfAKind, 4) => FiveOfAKind,
-            (FourOfAKind, 1) => FiveOfAKind,
-
-            (FullHouse, 3) => FiveOfAKind,
-            (FullHouse, 2) => FiveOfAKind,
-
-            (ThreeOfAKind, 3) => FourOfAKind,
-            (ThreeOfAKind, 1) => FourOfAKind,
-
-            (TwoPair, 2) => FourOfAKind,
-            (TwoPair, 1) => FullHouse,
-
-            (OnePair, 2) => ThreeOfAKind,
-            (OnePair, 1) => ThreeOfAKind,
+    let Some(csv) = csv else {
+        usage_error(USAGE, "`--part` needs `--report`");
+    };
 
-            (HighCard, 1) => OnePair,
+    let input = utils::cli::read_input_or_fail(path);
+    let report = rules
+        .report(&input)
+        .unwrap_or_else(|err| fail(format!("invalid input: {err}")));
 
-            _ => unreachable!(),
-        }
+    if csv {
+        print!("{}", report.to_csv());
+    } else {
+        println!("{report}");
     }
 }
-
-fn main() {
-    dbg!(part_one::part_one());
-    dbg!(part_two::part_two());
-}
-
-fn read_input() -> utils::ReadLines {
-    let filename = "input.txt";
-    utils::read_lines(filename).unwrap()
-}
-
-pub fn parse_line(value: String) -> (Hand, Bid) {
-    let (hand, bid) = {
-        let mut s = value.split(" ");
-        (s.next().unwrap(), s.next().unwrap())
-    };
-
-    (Hand::new(hand), bid.parse().unwrap())
-}
-
-use std::cmp::Ordering;
@@ -0,0 +1,736 @@
+pub struct Day8;
+
+impl utils::Solution for Day8 {
+    type Answer = usize;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        part_one::part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+// Every node it references has a mapping, so walks never get stuck.
+#[derive(Debug)]
+pub struct Parsed {
+    pub directions: Vec<Turn>,
+    pub mappings: Mappings,
+}
+
+// Like `Parsed`, but may reference nodes without a mapping,
+// so it can only be diagnosed and not walked by `Cycle::find`.
+#[derive(Debug)]
+pub struct Network {
+    pub directions: Vec<Turn>,
+    pub mappings: Mappings,
+}
+
+// Node names interned into dense ids, the defined nodes first in the order of the input.
+#[derive(Debug, Default)]
+pub struct Mappings {
+    names: Vec<String>,
+    ids: HashMap<String, u32>,
+    // the left and right node of every defined node
+    transitions: Vec<[u32; 2]>,
+}
+
+impl Mappings {
+    fn intern(&mut self, name: &str) -> u32 {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = u32::try_from(self.names.len()).expect("fewer than 2^32 nodes");
+        self.names.push(name.into());
+        self.ids.insert(name.into(), id);
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: u32) -> &str {
+        &self.names[id as usize]
+    }
+
+    // whether every node's name matches, by id
+    pub fn marks(&self, matches: impl Fn(&str) -> bool) -> Vec<bool> {
+        self.names.iter().map(|name| matches(name)).collect()
+    }
+
+    // the nodes with a mapping of their own
+    pub fn nodes(&self) -> impl Iterator<Item = u32> {
+        0..self.transitions.len() as u32
+    }
+
+    // referenced by a mapping but without one of their own
+    pub fn dangling(&self) -> impl Iterator<Item = u32> {
+        self.transitions.len() as u32..self.names.len() as u32
+    }
+
+    // left then right, none for dangling nodes
+    pub fn successors(&self, id: u32) -> &[u32] {
+        self.transitions
+            .get(id as usize)
+            .map_or(&[], |choices| choices.as_slice())
+    }
+
+    // `None` for dangling nodes
+    pub fn next(&self, id: u32, direction: Turn) -> Option<u32> {
+        let choices = self.transitions.get(id as usize)?;
+        Some(match direction {
+            Turn::Left => choices[0],
+            Turn::Right => choices[1],
+        })
+    }
+
+    // Graphviz, with dangling nodes dashed
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph network {\n");
+        for id in self.dangling() {
+            writeln!(dot, "    \"{}\" [style=dashed];", self.name(id)).unwrap();
+        }
+        for id in self.nodes() {
+            let name = self.name(id);
+            match self.transitions[id as usize] {
+                [left, right] if left == right => {
+                    writeln!(dot, "    \"{name}\" -> \"{}\";", self.name(left)).unwrap();
+                }
+                [left, right] => {
+                    for (label, next) in [("L", left), ("R", right)] {
+                        let next = self.name(next);
+                        writeln!(dot, "    \"{name}\" -> \"{next}\" [label={label}];").unwrap();
+                    }
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+// The index of the strongly connected component of every node, by Tarjan's algorithm.
+fn strongly_connected(mappings: &Mappings) -> Vec<usize> {
+    const UNVISITED: usize = usize::MAX;
+    let nodes = mappings.names.len();
+    let mut index = vec![UNVISITED; nodes];
+    let mut low_link = vec![0; nodes];
+    let mut on_stack = vec![false; nodes];
+    let mut stack = vec![];
+    let mut component = vec![0; nodes];
+    let (mut next_index, mut components) = (0, 0);
+
+    for root in 0..nodes as u32 {
+        if index[root as usize] != UNVISITED {
+            continue;
+        }
+
+        // the node and how many of its successors were visited, instead of recursing
+        let mut work = vec![(root, 0)];
+        while let Some((node, visited)) = work.pop() {
+            let v = node as usize;
+            if visited == 0 {
+                index[v] = next_index;
+                low_link[v] = next_index;
+                next_index += 1;
+                stack.push(node);
+                on_stack[v] = true;
+            }
+
+            if let Some(&successor) = mappings.successors(node).get(visited) {
+                work.push((node, visited + 1));
+                let w = successor as usize;
+                if index[w] == UNVISITED {
+                    work.push((successor, 0));
+                } else if on_stack[w] {
+                    low_link[v] = low_link[v].min(index[w]);
+                }
+                continue;
+            }
+
+            if low_link[v] == index[v] {
+                while let Some(member) = stack.pop() {
+                    on_stack[member as usize] = false;
+                    component[member as usize] = components;
+                    if member == node {
+                        break;
+                    }
+                }
+                components += 1;
+            }
+            if let Some((parent, _)) = work.last() {
+                let p = *parent as usize;
+                low_link[p] = low_link[p].min(low_link[v]);
+            }
+        }
+    }
+
+    component
+}
+
+// Why walks from the `..A` starts may never finish.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Diagnostics<'a> {
+    // by no start
+    pub unreachable: Vec<&'a str>,
+    pub dangling: Vec<&'a str>,
+    // every start with the strongly connected component its walk ends up in,
+    // a walk can only finish on an end node in it or on the way there
+    pub destinations: Vec<(&'a str, Vec<&'a str>)>,
+}
+
+impl Network {
+    pub fn diagnose(&self) -> Diagnostics<'_> {
+        let mappings = &self.mappings;
+        let nodes = mappings.names.len();
+        let starts = mappings
+            .nodes()
+            .filter(|id| mappings.name(*id).ends_with('A'))
+            .collect::<Vec<_>>();
+
+        let mut reached = vec![false; nodes];
+        let mut queue = starts.clone();
+        for start in &starts {
+            reached[*start as usize] = true;
+        }
+        while let Some(id) = queue.pop() {
+            for next in mappings.successors(id) {
+                if !reached[*next as usize] {
+                    reached[*next as usize] = true;
+                    queue.push(*next);
+                }
+            }
+        }
+
+        let component = strongly_connected(mappings);
+        let destinations = starts
+            .iter()
+            .map(|start| {
+                let last = component[self.last_node(*start) as usize];
+                let members = (0..nodes as u32)
+                    .filter(|id| component[*id as usize] == last)
+                    .map(|id| mappings.name(id))
+                    .collect();
+                (mappings.name(*start), members)
+            })
+            .collect();
+
+        Diagnostics {
+            unreachable: (0..nodes as u32)
+                .filter(|id| !reached[*id as usize])
+                .map(|id| mappings.name(id))
+                .collect(),
+            dangling: mappings.dangling().map(|id| mappings.name(id)).collect(),
+            destinations,
+        }
+    }
+
+    // the node where a walk from `start` repeats itself or gets stuck on a dangling node
+    fn last_node(&self, start: u32) -> u32 {
+        let mut seen = HashSet::new();
+        let mut walk_state = WalkState::new(start);
+        while seen.insert(walk_state) {
+            let direction = self.directions[walk_state.direction_index];
+            let Some(next) = self.mappings.next(walk_state.current, direction) else {
+                break;
+            };
+            walk_state = WalkState {
+                current: next,
+                direction_index: (walk_state.direction_index + 1) % self.directions.len(),
+            };
+        }
+        walk_state.current
+    }
+}
+
+impl std::fmt::Display for Diagnostics<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut line = |label: &str, nodes: &[&str]| {
+            write!(f, "{label}:")?;
+            nodes.iter().try_for_each(|node| write!(f, " {node}"))?;
+            writeln!(f)
+        };
+
+        line("unreachable", &self.unreachable)?;
+        line("dangling", &self.dangling)?;
+        for (start, component) in &self.destinations {
+            line(&format!("{start} ends up in"), component)?;
+        }
+        Ok(())
+    }
+}
+
+fn turn(value: char) -> Turn {
+    match value {
+        'L' => Turn::Left,
+        'R' => Turn::Right,
+        _ => unreachable!(),
+    }
+}
+
+use num::integer::{ExtendedGcd, Integer};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use utils::Turn;
+
+pub mod parse {
+    use super::*;
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{alphanumeric1, newline, one_of},
+        combinator::map,
+        error::context,
+        multi::{many1, separated_list1},
+        Offset,
+    };
+    use utils::parse::{parse_all, IResult};
+
+    #[derive(Debug)]
+    pub struct Mapping<'a> {
+        current: &'a str,
+        left: &'a str,
+        right: &'a str,
+    }
+
+    fn parse_node(input: &str) -> IResult<'_, &str> {
+        context("a node name", alphanumeric1)(input)
+    }
+
+    pub fn parse_mapping(input: &str) -> IResult<'_, Mapping<'_>> {
+        let (input, current) = parse_node(input)?;
+        let (input, _) = context("` = (`", tag(" = ("))(input)?;
+        let (input, left) = parse_node(input)?;
+        let (input, _) = context("`, `", tag(", "))(input)?;
+        let (input, right) = parse_node(input)?;
+        let (input, _) = context("`)`", tag(")"))(input)?;
+
+        Ok((
+            input,
+            Mapping {
+                current,
+                left,
+                right,
+            },
+        ))
+    }
+
+    fn intern(mappings: &[Mapping<'_>]) -> Mappings {
+        let mut interned = Mappings::default();
+        for mapping in mappings {
+            interned.intern(mapping.current);
+        }
+
+        interned.transitions = vec![[0, 0]; interned.names.len()];
+        for mapping in mappings {
+            let choices = [
+                interned.intern(mapping.left),
+                interned.intern(mapping.right),
+            ];
+            let id = interned.ids[mapping.current];
+            interned.transitions[id as usize] = choices;
+        }
+        interned
+    }
+
+    // rejects references to nodes without a mapping of their own, walks can't continue from those
+    pub fn parse_input(input: &str) -> Result<Parsed, utils::ParseError> {
+        let (directions, mappings) = parse_all(input, parse_parsed)?;
+
+        let defined = mappings
+            .iter()
+            .map(|mapping| mapping.current)
+            .collect::<HashSet<_>>();
+        if let Some(dangling) = mappings
+            .iter()
+            .flat_map(|mapping| [mapping.left, mapping.right])
+            .find(|node| !defined.contains(node))
+        {
+            return Err(utils::ParseError::at(
+                input,
+                &input[input.offset(dangling)..],
+                "a node with a mapping",
+            ));
+        }
+
+        Ok(Parsed {
+            mappings: intern(&mappings),
+            directions,
+        })
+    }
+
+    // like `parse_input` but keeps dangling references, to diagnose the network
+    pub fn parse_network(input: &str) -> Result<Network, utils::ParseError> {
+        let (directions, mappings) = parse_all(input, parse_parsed)?;
+
+        Ok(Network {
+            mappings: intern(&mappings),
+            directions,
+        })
+    }
+
+    fn parse_parsed(input: &str) -> IResult<'_, (Vec<Turn>, Vec<Mapping<'_>>)> {
+        let (input, directions) = many1(context("`L` or `R`", map(one_of("LR"), turn)))(input)?;
+        let (input, _) = newline(input)?;
+        let (input, _) = newline(input)?;
+
+        let (input, mappings) = separated_list1(newline, parse_mapping)(input)?;
+
+        Ok((input, (directions, mappings)))
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<usize, utils::SolveError> {
+        let parsed = parse::parse_input(input)?;
+
+        let start = parsed
+            .mappings
+            .id("AAA")
+            .ok_or_else(|| utils::ParseError::at(input, "", "a node named `AAA`"))?;
+
+        let is_end = parsed.mappings.marks(|node| node == "ZZZ");
+        let cycle = Cycle::find(&parsed, start, &is_end);
+        first_common_end(&[cycle])
+            .ok_or_else(|| utils::SolveError::no_answer("the walk from `AAA` never reaches `ZZZ`"))
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one_unreachable() {
+        assert_eq!(
+            part_one("L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)"),
+            Err(utils::SolveError::no_answer(
+                "the walk from `AAA` never reaches `ZZZ`"
+            ))
+        );
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(20093));
+    }
+}
+
+// Where a walk is: the node it's on and the index of the next direction to follow.
+// The walk only depends on this state, so it becomes periodic as soon as one repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WalkState {
+    current: u32,
+    direction_index: usize,
+}
+
+impl WalkState {
+    pub fn new(init: u32) -> Self {
+        Self {
+            current: init,
+            direction_index: 0,
+        }
+    }
+
+    pub fn node(&self) -> u32 {
+        self.current
+    }
+
+    pub fn next(&mut self, parsed: &Parsed) {
+        let direction = parsed.directions[self.direction_index];
+        self.current = parsed
+            .mappings
+            .next(self.current, direction)
+            .expect("a `Parsed` has no dangling references");
+        self.direction_index = (self.direction_index + 1) % parsed.directions.len();
+    }
+}
+
+// The steps after which a walk ends on an end node.
+// After `prefix` steps the walk repeats itself every `period` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+    // before entering the cycle, at least one step in
+    pub ends_before_cycle: Vec<usize>,
+    // as offsets from `prefix`, each below `period`
+    pub ends_in_cycle: Vec<usize>,
+}
+
+impl Cycle {
+    // `is_end` by node id, see `Mappings::marks`
+    pub fn find(parsed: &Parsed, start: u32, is_end: &[bool]) -> Self {
+        let mut seen = HashMap::new();
+        let mut history = vec![];
+        let mut walk_state = WalkState::new(start);
+
+        while let Entry::Vacant(entry) = seen.entry(walk_state) {
+            entry.insert(history.len());
+            history.push(walk_state);
+            walk_state.next(parsed);
+        }
+
+        let prefix = seen[&walk_state];
+        let period = history.len() - prefix;
+        let ends = |steps: std::ops::Range<usize>, offset: usize| {
+            steps
+                .filter(|step| is_end[history[step + offset].node() as usize])
+                .collect()
+        };
+
+        Self {
+            prefix,
+            period,
+            ends_before_cycle: ends(1..prefix, 0),
+            ends_in_cycle: ends(0..period, prefix),
+        }
+    }
+
+    pub fn ends_after(&self, steps: usize) -> bool {
+        if steps < self.prefix {
+            self.ends_before_cycle.contains(&steps)
+        } else {
+            self.ends_in_cycle
+                .contains(&((steps - self.prefix) % self.period))
+        }
+    }
+}
+
+// The fewest steps, at least one, after which every walk ends on an end node at once.
+// `None` when that never happens.
+pub fn first_common_end(cycles: &[Cycle]) -> Option<usize> {
+    // until the last walk enters its cycle it ends a finite number of times, try those first
+    let last = cycles.iter().max_by_key(|cycle| cycle.prefix)?;
+    if let Some(steps) = last
+        .ends_before_cycle
+        .iter()
+        .find(|steps| cycles.iter().all(|cycle| cycle.ends_after(**steps)))
+    {
+        return Some(*steps);
+    }
+
+    // from then on every walk ends on the steps congruent to one of its offsets
+    let mut congruences = vec![(0, 1)];
+    for cycle in cycles {
+        let period = cycle.period as i128;
+        congruences = congruences
+            .iter()
+            .flat_map(|congruence| {
+                cycle.ends_in_cycle.iter().filter_map(move |offset| {
+                    let remainder = (cycle.prefix + offset) as i128 % period;
+                    crt(*congruence, (remainder, period))
+                })
+            })
+            .collect();
+        congruences.sort_unstable();
+        congruences.dedup();
+    }
+
+    let least = last.prefix.max(1) as i128;
+    congruences
+        .into_iter()
+        .map(|(remainder, modulus)| least + (remainder - least).rem_euclid(modulus))
+        .min()
+        .and_then(|steps| usize::try_from(steps).ok())
+}
+
+// The numbers congruent to both `(remainder, modulus)` pairs, as a single such pair.
+// The moduli need not be coprime, `None` when there are no such numbers.
+fn crt((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let ExtendedGcd { gcd, x, .. } = m1.extended_gcd(&m2);
+    if (r2 - r1) % gcd != 0 {
+        return None;
+    }
+
+    let lcm = m1 / gcd * m2;
+    // `m1 * x` is `gcd` modulo `m2`
+    let k = ((r2 - r1) / gcd * x).rem_euclid(m2 / gcd);
+    Some(((r1 + m1 * k).rem_euclid(lcm), lcm))
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<usize, utils::SolveError> {
+        let parsed = parse::parse_input(input)?;
+        let is_end = parsed.mappings.marks(|node| node.ends_with('Z'));
+
+        let cycles = parsed
+            .mappings
+            .nodes()
+            .filter(|id| parsed.mappings.name(*id).ends_with('A'))
+            .map(|id| Cycle::find(&parsed, id, &is_end))
+            .collect::<Vec<_>>();
+
+        first_common_end(&cycles).ok_or_else(|| {
+            utils::SolveError::no_answer("the ghosts never all end on `..Z` nodes at once")
+        })
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(22103062509257));
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two_example() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        assert_eq!(part_two(input), Ok(6));
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two_offset_cycles() {
+        // 1A ends after 2, 4, 6... steps and 2A after 1, 4, 7... steps,
+        // the LCM of their first ends would be 2
+        let input = "L
+
+1A = (1B, 1B)
+1B = (1Z, 1Z)
+1Z = (1B, 1B)
+2A = (2Z, 2Z)
+2Z = (2C, 2C)
+2C = (2D, 2D)
+2D = (2Z, 2Z)";
+        assert_eq!(part_two(input), Ok(4));
+
+        // 3A only ends after an odd number of steps and 1A after an even one
+        let never = format!("{input}\n3A = (3Z, 3Z)\n3Z = (3B, 3B)\n3B = (3Z, 3Z)");
+        assert_eq!(
+            part_two(&never),
+            Err(utils::SolveError::no_answer(
+                "the ghosts never all end on `..Z` nodes at once"
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle() {
+        let parsed = parse::parse_input(
+            "LR
+
+AAA = (BBB, BBB)
+BBB = (CCC, ZZZ)
+CCC = (ZZZ, ZZZ)
+ZZZ = (CCC, CCC)",
+        )
+        .unwrap();
+        let is_end = parsed.mappings.marks(|node| node == "ZZZ");
+        assert_eq!(is_end, [false, false, false, true]);
+
+        // (AAA, 0) (BBB, 1) (ZZZ, 0) (CCC, 1) (ZZZ, 0)...
+        assert_eq!(
+            Cycle::find(&parsed, parsed.mappings.id("AAA").unwrap(), &is_end),
+            Cycle {
+                prefix: 2,
+                period: 2,
+                ends_before_cycle: vec![],
+                ends_in_cycle: vec![0],
+            }
+        );
+        // (CCC, 0) (ZZZ, 1) (CCC, 0)...
+        assert_eq!(
+            Cycle::find(&parsed, parsed.mappings.id("CCC").unwrap(), &is_end),
+            Cycle {
+                prefix: 0,
+                period: 2,
+                ends_before_cycle: vec![],
+                ends_in_cycle: vec![1],
+            }
+        );
+    }
+
+    #[test]
+    fn test_interning() {
+        let network = parse::parse_network("L\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)").unwrap();
+        let mappings = &network.mappings;
+
+        assert_eq!(mappings.nodes().collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(mappings.id("CCC"), Some(2));
+        assert_eq!(mappings.id("DDD"), None);
+        assert_eq!(mappings.name(mappings.next(0, Turn::Right).unwrap()), "CCC");
+        assert_eq!(mappings.next(1, Turn::Left), Some(0));
+        assert_eq!(mappings.next(2, Turn::Left), None);
+        assert_eq!(mappings.dangling().collect::<Vec<_>>(), [2]);
+
+        assert_eq!(
+            mappings.to_dot(),
+            r#"digraph network {
+    "CCC" [style=dashed];
+    "AAA" -> "BBB" [label=L];
+    "AAA" -> "CCC" [label=R];
+    "BBB" -> "AAA";
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_dangling() {
+        let input = "L\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)";
+        assert_eq!(
+            parse::parse_input(input).unwrap_err(),
+            utils::ParseError::new(3, 13, "a node with a mapping")
+        );
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let network = parse::parse_network(
+            "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, 22B)
+22B = (22B, QQQ)
+XXX = (XXX, XXX)
+UUU = (11Z, XXX)",
+        )
+        .unwrap();
+
+        let diagnostics = network.diagnose();
+        assert_eq!(
+            diagnostics,
+            Diagnostics {
+                unreachable: vec!["UUU"],
+                dangling: vec!["QQQ"],
+                destinations: vec![("11A", vec!["11B", "11Z"]), ("22A", vec!["QQQ"])],
+            }
+        );
+        assert_eq!(
+            diagnostics.to_string(),
+            "unreachable: UUU\ndangling: QQQ\n11A ends up in: 11B 11Z\n22A ends up in: QQQ\n"
+        );
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(crt((1, 4), (3, 6)), Some((9, 12)));
+        assert_eq!(crt((0, 4), (1, 6)), None);
+    }
+}
@@ -0,0 +1,425 @@
+use num::rational::Ratio;
+use num::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Signed, Zero};
+
+pub type SeqItem = i64;
+
+pub enum Predict {
+    Prev,
+    Next,
+    // the value at this index, the sequence starting at 0
+    At(i128),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredictError {
+    // the differences never become all zeros, or the sequence is empty
+    NotPolynomial,
+    Overflow,
+}
+
+impl std::fmt::Display for PredictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotPolynomial => write!(f, "numbers whose differences end in zeros"),
+            Self::Overflow => write!(f, "a prediction that fits in 128 bits"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Prediction {
+    pub value: i128,
+    // of the polynomial generating the sequence
+    pub degree: usize,
+}
+
+// What sequences can be made of, with exact arithmetic.
+pub trait Value:
+    Clone + PartialEq + Zero + CheckedAdd + CheckedSub + CheckedMul + From<i128>
+{
+}
+
+impl<T: Clone + PartialEq + Zero + CheckedAdd + CheckedSub + CheckedMul + From<i128>> Value for T {}
+
+// The sequence in Newton's forward difference form:
+// the first item of every row of differences, down to the last nonzero one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Newton<T = i128> {
+    leading: Vec<T>,
+}
+
+impl<T: Value> Newton<T> {
+    pub fn fit(seq: &[T]) -> Result<Self, PredictError> {
+        let mut row = seq.to_vec();
+        let mut leading = vec![];
+
+        // a single item could continue any way, it takes a row of zeros under two or more
+        while row.len() >= 2 {
+            leading.push(row[0].clone());
+            row = row
+                .windows(2)
+                .map(|window| {
+                    window[1]
+                        .checked_sub(&window[0])
+                        .ok_or(PredictError::Overflow)
+                })
+                .collect::<Result<_, _>>()?;
+            if row.iter().all(T::is_zero) {
+                return Ok(Self { leading });
+            }
+        }
+
+        Err(PredictError::NotPolynomial)
+    }
+
+    // 0 for constants
+    pub fn degree(&self) -> usize {
+        self.leading.len() - 1
+    }
+
+    // the sum of `binomial(k, j) * leading[j]`, `None` on overflow
+    pub fn at(&self, k: i128) -> Option<T> {
+        let mut binomial = 1i128;
+        let mut value = T::zero();
+        for (j, difference) in self.leading.iter().enumerate() {
+            let j = j as i128;
+            if j > 0 {
+                // exact, the product of `j` consecutive integers is divisible by `j!`
+                binomial = binomial.checked_mul(k.checked_sub(j - 1)?)? / j;
+            }
+            value = value.checked_add(&T::from(binomial).checked_mul(difference)?)?;
+        }
+        Some(value)
+    }
+}
+
+pub fn predict<T: Into<i128> + Copy>(mode: Predict, seq: &[T]) -> Result<Prediction, PredictError> {
+    let seq = seq.iter().map(|item| (*item).into()).collect::<Vec<i128>>();
+    let newton = Newton::fit(&seq)?;
+    let k = match mode {
+        Predict::Prev => -1,
+        Predict::Next => seq.len() as i128,
+        Predict::At(k) => k,
+    };
+
+    Ok(Prediction {
+        value: newton.at(k).ok_or(PredictError::Overflow)?,
+        degree: newton.degree(),
+    })
+}
+
+pub type Rational = Ratio<i128>;
+
+// The polynomial generating a sequence, the item at index `x` being its value at `x`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    // of `x^0`, `x^1`...
+    coefficients: Vec<Rational>,
+}
+
+impl Polynomial {
+    pub fn fit(seq: &[Rational]) -> Result<Self, PredictError> {
+        Self::from_newton(&Newton::fit(seq)?).ok_or(PredictError::Overflow)
+    }
+
+    fn from_newton(newton: &Newton<Rational>) -> Option<Self> {
+        let mut coefficients = vec![Rational::zero(); newton.leading.len()];
+        // `binomial(x, j)` as a polynomial
+        let mut binomial = vec![Rational::one()];
+
+        for (j, difference) in newton.leading.iter().enumerate() {
+            if j > 0 {
+                // times `(x - j + 1) / j`
+                let shift = Rational::from(j as i128 - 1);
+                let divisor = Rational::from(j as i128);
+                let mut next = vec![Rational::zero(); binomial.len() + 1];
+                for (i, coefficient) in binomial.iter().enumerate() {
+                    next[i + 1] = next[i + 1].checked_add(coefficient)?;
+                    next[i] = next[i].checked_sub(&coefficient.checked_mul(&shift)?)?;
+                }
+                binomial = next
+                    .iter()
+                    .map(|coefficient| coefficient.checked_div(&divisor))
+                    .collect::<Option<_>>()?;
+            }
+
+            for (total, coefficient) in coefficients.iter_mut().zip(&binomial) {
+                *total = total.checked_add(&coefficient.checked_mul(difference)?)?;
+            }
+        }
+
+        Some(Self { coefficients })
+    }
+
+    pub fn coefficients(&self) -> &[Rational] {
+        &self.coefficients
+    }
+
+    // 0 for constants, including zero
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    // `None` on overflow
+    pub fn at(&self, x: Rational) -> Option<Rational> {
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(Rational::zero(), |value, coefficient| {
+                value.checked_mul(&x)?.checked_add(coefficient)
+            })
+    }
+}
+
+impl std::fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coefficient)| !coefficient.is_zero());
+
+        let mut first = true;
+        for (power, coefficient) in terms {
+            match (first, coefficient.is_negative()) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+
+            let coefficient = coefficient.abs();
+            match power {
+                0 => write!(f, "{coefficient}")?,
+                _ if coefficient.is_one() => {}
+                _ if coefficient.is_integer() => write!(f, "{coefficient}")?,
+                _ => write!(f, "({coefficient})")?,
+            }
+            match power {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{power}")?,
+            }
+        }
+
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+// the polynomial of every line, which may be made of fractions like `-3/4`
+pub fn fit_lines(input: &str) -> Result<Vec<Result<Polynomial, PredictError>>, utils::ParseError> {
+    utils::parse::parse_lines(input, |line| {
+        parse_line::<Rational>(line).map(|seq| Polynomial::fit(&seq))
+    })
+}
+
+// sums the predictions of every line
+fn predict_all(input: &str, mode: impl Fn() -> Predict) -> Result<i128, utils::ParseError> {
+    let values = utils::parse::parse_lines(input, |line| {
+        let seq = parse_line::<SeqItem>(line)?;
+        predict(mode(), &seq)
+            .map(|prediction| prediction.value)
+            .map_err(|err| utils::ParseError::new(1, 1, err.to_string()))
+    })?;
+
+    values
+        .into_iter()
+        .try_fold(0i128, i128::checked_add)
+        .ok_or_else(|| utils::ParseError::at(input, "", PredictError::Overflow.to_string()))
+}
+
+mod part_one {
+    use super::*;
+
+    pub fn part_one(input: &str) -> Result<i128, utils::ParseError> {
+        predict_all(input, || Predict::Next)
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input()), Ok(1637452029));
+    }
+}
+
+mod part_two {
+    use super::*;
+
+    pub fn part_two(input: &str) -> Result<i128, utils::ParseError> {
+        predict_all(input, || Predict::Prev)
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input()), Ok(908));
+    }
+}
+
+#[cfg(test)]
+fn input() -> String {
+    std::fs::read_to_string("input.txt").unwrap()
+}
+
+// numbers separated by any whitespace
+fn parse_line<T: std::str::FromStr>(value: &str) -> Result<Vec<T>, utils::ParseError> {
+    value
+        .split_whitespace()
+        .map(|item| {
+            item.parse().map_err(|_| {
+                let offset = item.as_ptr() as usize - value.as_ptr() as usize;
+                utils::ParseError::new(1, value[..offset].chars().count() + 1, "a number")
+            })
+        })
+        .collect()
+}
+
+pub struct Day9;
+
+impl utils::Solution for Day9 {
+    type Answer = i128;
+
+    fn part_one(input: &str) -> Result<Self::Answer, utils::SolveError> {
+        Ok(part_one::part_one(input)?)
+    }
+
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, utils::SolveError> {
+        Ok(Some(part_two::part_two(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line::<SeqItem>("0 -3 6"), Ok(vec![0, -3, 6]));
+        assert_eq!(
+            parse_line::<SeqItem>("0 3 x 9"),
+            Err(utils::ParseError::new(1, 5, "a number"))
+        );
+        assert_eq!(parse_line::<SeqItem>("  -1\t 2   -3 "), Ok(vec![-1, 2, -3]));
+        assert_eq!(
+            parse_line::<SeqItem>(" 1\t\t2.5"),
+            Err(utils::ParseError::new(1, 5, "a number"))
+        );
+        assert_eq!(
+            parse_line::<Rational>("1/2 -3 4/6"),
+            Ok(vec![
+                Rational::new(1, 2),
+                Rational::from(-3),
+                Rational::new(2, 3)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_predict() {
+        let predict = |mode, seq: &[SeqItem]| predict(mode, seq).map(|p| (p.value, p.degree));
+
+        assert_eq!(predict(Predict::Next, &[0, 3, 6, 9, 12, 15]), Ok((18, 1)));
+        assert_eq!(
+            predict(Predict::Prev, &[10, 13, 16, 21, 30, 45]),
+            Ok((5, 3))
+        );
+        assert_eq!(predict(Predict::Next, &[7, 7]), Ok((7, 0)));
+        assert_eq!(predict(Predict::Next, &[0, 0, 0]), Ok((0, 0)));
+
+        // squares, far away in both directions
+        let squares = [0, 1, 4, 9, 16];
+        assert_eq!(predict(Predict::At(3), &squares), Ok((9, 2)));
+        assert_eq!(predict(Predict::At(-7), &squares), Ok((49, 2)));
+        assert_eq!(
+            predict(Predict::At(1_000_000_000_000), &squares),
+            Ok((1_000_000_000_000_000_000_000_000, 2))
+        );
+        assert_eq!(
+            predict(Predict::At(i128::MAX), &squares),
+            Err(PredictError::Overflow)
+        );
+
+        // cubes
+        let cubes = [-8, -1, 0, 1, 8, 27];
+        assert_eq!(predict(Predict::At(-10), &cubes), Ok((-1728, 3)));
+    }
+
+    #[test]
+    fn test_not_polynomial() {
+        for seq in [&[][..], &[5], &[1, 2, 4, 8, 16], &[0, 1]] {
+            assert_eq!(
+                predict(Predict::Next, seq),
+                Err(PredictError::NotPolynomial),
+                "{seq:?}"
+            );
+        }
+        assert_eq!(
+            part_one::part_one("0 3 6\n1 2 4 8"),
+            Err(utils::ParseError::new(
+                2,
+                1,
+                "numbers whose differences end in zeros"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_predict_wide() {
+        let big = i64::MAX as i128;
+        assert_eq!(
+            predict(Predict::Next, &[big, 2 * big, 3 * big]),
+            Ok(Prediction {
+                value: 4 * big,
+                degree: 1
+            })
+        );
+        assert_eq!(
+            predict(Predict::Next, &[i128::MIN, i128::MAX]),
+            Err(PredictError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_polynomial() {
+        let fit = |seq: &str| {
+            let seq = parse_line::<Rational>(seq).unwrap();
+            Polynomial::fit(&seq).map(|polynomial| polynomial.to_string())
+        };
+
+        assert_eq!(
+            fit("10 13 16 21 30 45").as_deref(),
+            Ok("(1/3)x^3 - x^2 + (11/3)x + 10")
+        );
+        // the triangular numbers
+        assert_eq!(fit("0 1 3 6 10").as_deref(), Ok("(1/2)x^2 + (1/2)x"));
+        assert_eq!(fit("1/2 -1/2 -3/2").as_deref(), Ok("-x + 1/2"));
+        assert_eq!(fit("0 0").as_deref(), Ok("0"));
+        assert_eq!(fit("1 2 4 8 16 32"), Err(PredictError::NotPolynomial));
+
+        let cubes = Polynomial::fit(&[0, 1, 8, 27, 64].map(Rational::from)).unwrap();
+        assert_eq!(cubes.degree(), 3);
+        assert_eq!(
+            cubes.coefficients(),
+            [0, 0, 0, 1].map(Rational::from).as_slice()
+        );
+        assert_eq!(cubes.at(Rational::new(1, 2)), Some(Rational::new(1, 8)));
+    }
+
+    #[test]
+    fn test_fit_lines() {
+        let fits = fit_lines("0 1 2\n1 3 1 3 1 3\n1/4 1/2 3/4").unwrap();
+        assert_eq!(fits[0].as_ref().map(Polynomial::degree), Ok(1));
+        assert_eq!(fits[1], Err(PredictError::NotPolynomial));
+        assert_eq!(fits[2].as_ref().map(Polynomial::degree), Ok(1));
+
+        assert_eq!(
+            fit_lines("1 2\n1 2/0"),
+            Err(utils::ParseError::new(2, 3, "a number"))
+        );
+    }
+}
@@ -0,0 +1,34 @@
+// Helpers for the day binaries that take flags besides the input path.
+
+use crate::Solution;
+use std::fmt::Display;
+
+// The arguments after the program name,
+// or `None` once `S` ran like `crate::run` because none of them is a flag.
+pub fn flags_or_run<S: Solution>() -> Option<Vec<String>> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg.starts_with("--")) {
+        Some(args)
+    } else {
+        crate::run::<S>();
+        None
+    }
+}
+
+// For a misused command line, exits with 2 after the message and `usage`.
+pub fn usage_error(usage: &str, message: impl Display) -> ! {
+    eprintln!("{message}\n\n{usage}");
+    std::process::exit(2);
+}
+
+// For input that can't be read or solved, exits with 1 after the message.
+pub fn fail(message: impl Display) -> ! {
+    eprintln!("{message}");
+    std::process::exit(1);
+}
+
+// from stdin without a path
+pub fn read_input_or_fail(path: Option<&str>) -> String {
+    crate::read_input_from(path.unwrap_or("-"))
+        .unwrap_or_else(|err| fail(format!("failed to read the input: {err}")))
+}
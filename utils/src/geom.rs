@@ -0,0 +1,253 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// `x` grows to the right and `y` grows down, like the rows of a `Grid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn step(self, direction: impl Into<Dir8>) -> Self {
+        self + direction.into().offset()
+    }
+
+    pub fn step_n(self, direction: impl Into<Dir8>, n: isize) -> Self {
+        self + direction.into().offset() * n
+    }
+
+    // `None` when the step leaves `0..width` x `0..height`
+    pub fn step_within(
+        self,
+        direction: impl Into<Dir8>,
+        width: usize,
+        height: usize,
+    ) -> Option<Self> {
+        Some(self.step(direction)).filter(|p| p.within(width, height))
+    }
+
+    pub fn within(self, width: usize, height: usize) -> bool {
+        (0..width as isize).contains(&self.x) && (0..height as isize).contains(&self.y)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl std::fmt::Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+// North is up, towards smaller `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    // clockwise
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    pub fn offset(self) -> Point {
+        Dir8::from(self).offset()
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    // clockwise
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    pub fn offset(self) -> Point {
+        let (x, y) = match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        };
+        Point::new(x, y)
+    }
+
+    // by 45 degrees
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(value: Dir4) -> Self {
+        match value {
+            Dir4::North => Self::North,
+            Dir4::East => Self::East,
+            Dir4::South => Self::South,
+            Dir4::West => Self::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.offset() + dir.reverse().offset(), Point::ORIGIN);
+        }
+        assert_eq!(Dir4::North.turn(Turn::Right), Dir4::East);
+        assert_eq!(Dir4::North.turn(Turn::Left), Dir4::West);
+
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.offset() + dir.reverse().offset(), Point::ORIGIN);
+        }
+        assert_eq!(Dir8::North.turn_right(), Dir8::NorthEast);
+    }
+
+    #[test]
+    fn test_point() {
+        let p = Point::new(2, -3);
+        assert_eq!(p.step(Dir4::North), Point::new(2, -4));
+        assert_eq!(p.step(Dir8::SouthWest), Point::new(1, -2));
+        assert_eq!(p.step_n(Dir4::East, 5), Point::new(7, -3));
+        assert_eq!(p.manhattan(Point::ORIGIN), 5);
+        assert_eq!(p - p, Point::ORIGIN);
+        assert_eq!(-p * 2, Point::new(-4, 6));
+    }
+
+    #[test]
+    fn test_step_within() {
+        let p = Point::new(0, 1);
+        assert_eq!(p.step_within(Dir4::West, 3, 2), None);
+        assert_eq!(p.step_within(Dir4::South, 3, 2), None);
+        assert_eq!(p.step_within(Dir4::North, 3, 2), Some(Point::new(0, 0)));
+    }
+}
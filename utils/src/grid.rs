@@ -0,0 +1,262 @@
+use crate::geom::{Dir4, Dir8, Point};
+use crate::ParseError;
+
+// Rectangular grid stored row by row.
+// Cells are addressed by `Point`: `x` is the column, `y` the row, both from the top left.
+// Signed so that stepping off the border yields `None` instead of an underflow.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    // `expected` describes the chars `cell` accepts, see `parse::parse_rows`
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        crate::parse::parse_rows(input, expected, cell).map(Self::from_iter)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.within(self.width, self.height)
+    }
+
+    fn index(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index(p).map(|i| &mut self.cells[i])
+    }
+
+    // `None` when the step leaves the grid
+    pub fn step(&self, p: Point, direction: impl Into<Dir8>) -> Option<Point> {
+        p.step_within(direction, self.width, self.height)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on 0
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} out of a grid {} wide",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| {
+            let (y, x) = (i / self.width, i % self.width);
+            (Point::new(x as isize, y as isize), cell)
+        })
+    }
+
+    // clockwise from north, skipping the ones outside of the grid
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.neighbour(p, dir))
+    }
+
+    // clockwise from north, skipping the ones outside of the grid
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.neighbour(p, dir))
+    }
+
+    fn neighbour(&self, p: Point, direction: impl Into<Dir8>) -> Option<(Point, &T)> {
+        let p = p.step(direction);
+        self.get(p).map(|cell| (p, cell))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        (0..self.width)
+            .map(|x| self.column(x).cloned().collect::<Vec<_>>())
+            .collect()
+    }
+
+    // mirrors left and right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rows()
+            .map(|row| row.iter().rev().cloned().collect())
+            .collect()
+    }
+
+    // mirrors top and bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rows().rev().map(<[T]>::to_vec).collect()
+    }
+
+    // clockwise
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transpose().flip_horizontal()
+    }
+
+    // counterclockwise
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transpose().flip_vertical()
+    }
+}
+
+// collects rows, which must all be of the same length
+impl<T> FromIterator<Vec<T>> for Grid<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(rows: I) -> Self {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for row in rows {
+            let width = *width.get_or_insert(row.len());
+            assert_eq!(width, row.len(), "row {height} has a different length");
+            cells.extend(row);
+            height += 1;
+        }
+
+        Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        }
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 0)), Some(&'c'));
+        assert_eq!(grid.get(Point::new(0, 1)), Some(&'d'));
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(
+            grid.step(Point::new(0, 1), Dir4::North),
+            Some(Point::new(0, 0))
+        );
+        assert_eq!(grid.step(Point::new(0, 1), Dir4::South), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [(Point::new(1, 0), &'b'), (Point::new(0, 1), &'d')]
+        );
+        assert_eq!(
+            grid.neighbours8(Point::new(1, 1))
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "bcfda"
+        );
+    }
+}
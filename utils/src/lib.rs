@@ -1,15 +1,103 @@
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-pub fn read_lines<P>(filename: P) -> io::Result<ReadLines>
+pub mod cli;
+pub mod geom;
+pub mod grid;
+pub mod parse;
+pub mod search;
+
+pub use geom::{Dir4, Dir8, Point, Turn};
+pub use grid::Grid;
+pub use parse::ParseError;
+
+// Reads the whole puzzle input from the file passed as the first argument,
+// or from stdin when no argument is given.
+pub fn read_input() -> io::Result<String> {
+    match std::env::args().nth(1) {
+        Some(path) => read_input_from(path),
+        None => read_input_from("-"),
+    }
+}
+
+// "-" stands for stdin
+pub fn read_input_from<P>(path: P) -> io::Result<String>
 where
     P: AsRef<Path>,
 {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+    if path.as_ref() == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+// Why a part has no answer, so a malformed input can be told apart
+// from a well formed one the puzzle can't be solved for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    // like a goal nothing reaches, says what is missing
+    NoAnswer(String),
 }
 
-pub type ReadLines = io::Lines<io::BufReader<File>>;
+impl SolveError {
+    pub fn no_answer(missing: impl Into<String>) -> Self {
+        Self::NoAnswer(missing.into())
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "invalid input: {err}"),
+            Self::NoAnswer(missing) => write!(f, "no answer: {missing}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+// Implemented by every day, so the `aoc` runner can call the parts
+// without knowing how each day solves its input.
+pub trait Solution {
+    type Answer: std::fmt::Display;
+
+    fn part_one(input: &str) -> Result<Self::Answer, SolveError>;
+
+    // Ok(None) when the part is not solved yet
+    fn part_two(input: &str) -> Result<Option<Self::Answer>, SolveError>;
+}
+
+pub fn print_answers<S: Solution>(input: &str) -> Result<(), SolveError> {
+    println!("part one: {}", S::part_one(input)?);
+    match S::part_two(input)? {
+        Some(answer) => println!("part two: {answer}"),
+        None => println!("part two: not solved"),
+    }
+    Ok(())
+}
+
+// Entry point of the day binaries, reports bad input or a missing answer instead of panicking.
+pub fn run<S: Solution>() {
+    let input = match read_input() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("failed to read the input: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(err) = print_answers::<S>(&input) {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
 
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::{self, Read};
 use std::path::Path;
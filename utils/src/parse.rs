@@ -0,0 +1,195 @@
+use nom::error::{VerboseError, VerboseErrorKind};
+
+// nom result carrying enough context to build a `ParseError`,
+// wrap parsers in `nom::error::context` to name what they expect
+pub type IResult<'a, O> = nom::IResult<&'a str, O, VerboseError<&'a str>>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // both 1-based
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    // `rest` is the unparsed suffix of `source`
+    pub fn at(source: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let consumed = &source[..source.len() - rest.len()];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.rsplit('\n').next().unwrap().chars().count() + 1;
+        Self::new(line, column, expected)
+    }
+
+    pub fn from_nom(source: &str, err: nom::Err<VerboseError<&str>>) -> Self {
+        let err = match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => err,
+            nom::Err::Incomplete(_) => return Self::at(source, "", "more input"),
+        };
+
+        // a context names the whole token, so point at where it starts
+        if let Some((rest, context)) = err.errors.iter().find_map(|(rest, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some((rest, context)),
+            _ => None,
+        }) {
+            return Self::at(source, rest, *context);
+        }
+
+        let (rest, kind) = &err.errors[0];
+
+        let expected = match kind {
+            VerboseErrorKind::Char('\n') => "a line break".into(),
+            VerboseErrorKind::Char(c) => format!("`{c}`"),
+            VerboseErrorKind::Nom(nom::error::ErrorKind::Digit) => "a number".into(),
+            VerboseErrorKind::Nom(nom::error::ErrorKind::Space) => "whitespace".into(),
+            VerboseErrorKind::Nom(nom::error::ErrorKind::Eof) => "end of input".into(),
+            VerboseErrorKind::Nom(kind) => format!("{kind:?}").to_lowercase(),
+            VerboseErrorKind::Context(context) => context.to_string(),
+        };
+
+        Self::at(source, rest, expected)
+    }
+
+    // for errors found in a slice starting `lines` lines into the input
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Runs `parser` over the whole `input`, only trailing whitespace may be left over.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<'a, O>,
+) -> Result<O, ParseError> {
+    use nom::{character::complete::multispace0, combinator::eof, sequence::terminated};
+
+    terminated(parser, terminated(multispace0, eof))(input)
+        .map(|(_, output)| output)
+        .map_err(|err| ParseError::from_nom(input, err))
+}
+
+// Parses every line on its own, errors point at the line within the whole input.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parse_line: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|err| err.offset_lines(i)))
+        .collect()
+}
+
+// Maps every char of a rectangular block of lines, `expected` describes the chars `cell` accepts.
+pub fn parse_rows<T>(
+    input: &str,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let rows = parse_lines(input, |line| {
+        line.chars()
+            .enumerate()
+            .map(|(i, c)| cell(c).ok_or_else(|| ParseError::new(1, i + 1, expected)))
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+
+    match rows.first() {
+        None => Err(ParseError::new(1, 1, expected)),
+        Some(first) => {
+            let width = first.len();
+            match rows.iter().position(|row| row.len() != width) {
+                None => Ok(rows),
+                Some(i) => Err(ParseError::new(
+                    i + 1,
+                    width.min(rows[i].len()) + 1,
+                    format!("a row of {width} cells"),
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let source = "ab\ncde\nf";
+        assert_eq!(
+            ParseError::at(source, source, "x"),
+            ParseError::new(1, 1, "x")
+        );
+        assert_eq!(
+            ParseError::at(source, "de\nf", "x"),
+            ParseError::new(2, 2, "x")
+        );
+        assert_eq!(ParseError::at(source, "", "x"), ParseError::new(3, 2, "x"));
+    }
+
+    #[test]
+    fn test_parse_all() {
+        use nom::{bytes::complete::tag, character::complete, error::context};
+
+        fn parse(input: &str) -> IResult<'_, (u32, u32)> {
+            let (input, a) = complete::u32(input)?;
+            let (input, _) = context("`,`", tag(","))(input)?;
+            let (input, b) = complete::u32(input)?;
+            Ok((input, (a, b)))
+        }
+
+        assert_eq!(parse_all("1,2\n", parse), Ok((1, 2)));
+        assert_eq!(parse_all("1;2", parse), Err(ParseError::new(1, 2, "`,`")));
+        assert_eq!(
+            parse_all("1,x", parse),
+            Err(ParseError::new(1, 3, "a number"))
+        );
+        assert_eq!(
+            parse_all("1,2 3", parse),
+            Err(ParseError::new(1, 5, "end of input"))
+        );
+    }
+
+    #[test]
+    fn test_parse_rows() {
+        let cell = |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        };
+
+        assert_eq!(
+            parse_rows(".#\n#.\n", "`.` or `#`", cell),
+            Ok(vec![vec![false, true], vec![true, false]])
+        );
+        assert_eq!(
+            parse_rows(".#\n#?", "`.` or `#`", cell),
+            Err(ParseError::new(2, 2, "`.` or `#`"))
+        );
+        assert_eq!(
+            parse_rows(".#\n#", "`.` or `#`", cell),
+            Err(ParseError::new(2, 2, "a row of 2 cells"))
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+// A graph to search for the cheapest path from any start state to a goal state.
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+
+    fn starts(&self) -> Vec<Self::State>;
+
+    fn neighbours(&self, state: &Self::State) -> Vec<Self::State>;
+
+    // of moving from `from` to its neighbour `to`
+    fn cost(&self, from: &Self::State, to: &Self::State) -> usize;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: usize,
+    // from the start to the goal, both included
+    pub states: Vec<S>,
+}
+
+pub fn dijkstra<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    astar(problem, |_| 0)
+}
+
+// `heuristic` estimates the cost left to a goal,
+// it must never overestimate it or the path found may not be the cheapest.
+pub fn astar<P: SearchProblem>(
+    problem: &P,
+    heuristic: impl Fn(&P::State) -> usize,
+) -> Option<Path<P::State>> {
+    // best known cost of every state and the state it was reached from
+    let mut best = HashMap::<P::State, (usize, Option<P::State>)>::new();
+    // the heap refers to states by index so that they need not be `Ord`
+    let mut states = vec![];
+    let mut heap = BinaryHeap::new();
+
+    for start in problem.starts() {
+        if best.contains_key(&start) {
+            continue;
+        }
+        best.insert(start.clone(), (0, None));
+        heap.push(Reverse((heuristic(&start), 0, states.len())));
+        states.push(start);
+    }
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        let state = states[i].clone();
+
+        // a cheaper way to it was found after this one was queued
+        if cost > best[&state].0 {
+            continue;
+        }
+
+        if problem.is_goal(&state) {
+            return Some(Path {
+                cost,
+                states: reconstruct(&best, state),
+            });
+        }
+
+        for next in problem.neighbours(&state) {
+            let next_cost = cost + problem.cost(&state, &next);
+            if best
+                .get(&next)
+                .is_some_and(|(known, _)| *known <= next_cost)
+            {
+                continue;
+            }
+            best.insert(next.clone(), (next_cost, Some(state.clone())));
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                states.len(),
+            )));
+            states.push(next);
+        }
+    }
+
+    None
+}
+
+fn reconstruct<S: Clone + Eq + Hash>(best: &HashMap<S, (usize, Option<S>)>, goal: S) -> Vec<S> {
+    let mut path = vec![goal];
+    while let Some((_, Some(previous))) = best.get(path.last().unwrap()) {
+        path.push(previous.clone());
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // walks right along a number line, one step costs 1 and a jump of 3 costs 2
+    struct Line {
+        goal: u32,
+    }
+
+    impl SearchProblem for Line {
+        type State = u32;
+
+        fn starts(&self) -> Vec<u32> {
+            vec![0]
+        }
+
+        fn neighbours(&self, state: &u32) -> Vec<u32> {
+            vec![state + 1, state + 3]
+                .into_iter()
+                .filter(|next| *next <= self.goal)
+                .collect()
+        }
+
+        fn cost(&self, from: &u32, to: &u32) -> usize {
+            if to - from == 1 {
+                1
+            } else {
+                2
+            }
+        }
+
+        fn is_goal(&self, state: &u32) -> bool {
+            *state == self.goal
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // several cheapest paths, e.g. 0 1 4 7 and 0 3 6 7
+        let path = dijkstra(&Line { goal: 7 }).unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.len(), 4);
+        assert_eq!((path.states[0], path.states[3]), (0, 7));
+
+        assert_eq!(
+            dijkstra(&Line { goal: 0 }),
+            Some(Path {
+                cost: 0,
+                states: vec![0]
+            })
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let line = Line { goal: 9 };
+        let to_goal = |state: &u32| (line.goal - state) as usize / 3 * 2;
+        assert_eq!(
+            astar(&line, to_goal),
+            Some(Path {
+                cost: 6,
+                states: vec![0, 3, 6, 9]
+            })
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        struct Stuck;
+
+        impl SearchProblem for Stuck {
+            type State = u8;
+
+            fn starts(&self) -> Vec<u8> {
+                vec![0]
+            }
+
+            fn neighbours(&self, _: &u8) -> Vec<u8> {
+                vec![]
+            }
+
+            fn cost(&self, _: &u8, _: &u8) -> usize {
+                1
+            }
+
+            fn is_goal(&self, state: &u8) -> bool {
+                *state == 1
+            }
+        }
+
+        assert_eq!(dijkstra(&Stuck), None);
+    }
+}